@@ -23,11 +23,11 @@ where
 }
 
 fn main() {
-    let mut foo = Foo::default();
-    foo.set_private(1);
-    (*foo.private_mut()) += 1;
-    assert_eq!(*foo.private(), 2);
-    foo = foo.with_private(3);
-    assert_eq!(*foo.private(), 3);
-    assert_eq!(*foo.arc(), 0);
+    let mut val = Foo::default();
+    val.set_private(1);
+    (*val.private_mut()) += 1;
+    assert_eq!(*val.private(), 2);
+    val = val.with_private(3);
+    assert_eq!(*val.private(), 3);
+    assert_eq!(*val.arc(), 0);
 }
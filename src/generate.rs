@@ -1,15 +1,23 @@
 use proc_macro_error2::abort;
 use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use quote::ToTokens;
 use syn::{
-    self, Expr, Field, Lit, Meta, MetaNameValue, Visibility, ext::IdentExt, spanned::Spanned,
+    self, Data, DataStruct, DeriveInput, Expr, ExprLit, Field, GenericArgument, GenericParam, Lit,
+    LitStr, Member, Meta, MetaNameValue, PathArguments, Token, Type, Visibility, ext::IdentExt,
+    parse::ParseStream, parse_quote, punctuated::Punctuated, spanned::Spanned,
 };
 
-use self::GenMode::{Get, GetClone, GetCopy, GetMut, Set, SetWith};
-use super::parse_attr;
+use self::GenMode::{
+    Atomic, Cell, Collection, Get, GetClone, GetCopy, GetDeref, GetIter, GetIterMut, GetMut,
+    GetOption, GetOptionMut, Lock, Map, Pin, RefCell, Replace, Set, SetTry, SetWith, Shared, Swap,
+    Take, Unpin, Update,
+};
+use super::{parse_attr, parse_rename_all};
 
 pub struct GenParams {
     pub mode: GenMode,
     pub global_attr: Option<Meta>,
+    pub rename_all: Option<LitStr>,
 }
 
 #[derive(PartialEq, Eq, Copy, Clone)]
@@ -17,56 +25,320 @@ pub enum GenMode {
     Get,
     GetClone,
     GetCopy,
+    GetDeref,
+    GetOption,
+    GetIter,
     GetMut,
+    GetOptionMut,
+    GetIterMut,
+    Update,
     Set,
+    SetTry,
     SetWith,
+    Map,
+    Take,
+    Replace,
+    Swap,
+    Collection,
+    Cell,
+    RefCell,
+    Lock,
+    Atomic,
+    Shared,
+    Pin,
+    Unpin,
 }
 
 impl GenMode {
+    pub const ALL: &'static [GenMode] = &[
+        Get,
+        GetClone,
+        GetCopy,
+        GetDeref,
+        GetOption,
+        GetIter,
+        GetMut,
+        GetOptionMut,
+        GetIterMut,
+        Update,
+        Set,
+        SetTry,
+        SetWith,
+        Map,
+        Take,
+        Replace,
+        Swap,
+        Collection,
+        Cell,
+        RefCell,
+        Lock,
+        Atomic,
+        Shared,
+        Pin,
+        Unpin,
+    ];
+
     pub fn name(self) -> &'static str {
         match self {
             Get => "get",
             GetClone => "get_clone",
             GetCopy => "get_copy",
+            GetDeref => "get_deref",
+            GetOption => "get_option",
+            GetIter => "get_iter",
             GetMut => "get_mut",
+            GetOptionMut => "get_option_mut",
+            GetIterMut => "get_iter_mut",
+            Update => "update",
             Set => "set",
+            SetTry => "set_try",
             SetWith => "set_with",
+            Map => "map",
+            Take => "take",
+            Replace => "replace",
+            Swap => "swap",
+            Collection => "collection",
+            Cell => "cell",
+            RefCell => "ref_cell",
+            Lock => "lock",
+            Atomic => "atomic",
+            Shared => "shared",
+            Pin => "pin",
+            Unpin => "unpin",
         }
     }
 
     pub fn prefix(self) -> &'static str {
         match self {
-            Get | GetClone | GetCopy | GetMut => "",
+            Get | GetClone | GetCopy | GetDeref | GetOption | GetIter => "",
+            GetMut | GetOptionMut | GetIterMut => "",
             Set => "set_",
+            SetTry => "try_set_",
             SetWith => "with_",
+            Update => "update_",
+            Map => "map_",
+            Take => "take_",
+            Replace => "replace_",
+            Swap => "swap_",
+            Lock => "lock_",
+            Collection | Cell | RefCell | Atomic | Shared | Pin | Unpin => "",
         }
     }
 
     pub fn suffix(self) -> &'static str {
         match self {
-            Get | GetClone | GetCopy | Set | SetWith => "",
-            GetMut => "_mut",
+            Get | GetClone | GetCopy | GetDeref | GetOption => "",
+            Update | Set | SetTry | SetWith | Map | Take | Replace | Swap => "",
+            Collection | Cell | RefCell | Lock | Atomic | Shared => "",
+            GetMut | GetOptionMut | Unpin => "_mut",
+            Pin => "_pin_mut",
+            GetIter => "_iter",
+            GetIterMut => "_iter_mut",
+        }
+    }
+
+    pub fn is_get(self) -> bool {
+        match self {
+            Get | GetClone | GetCopy | GetDeref | GetOption | GetIter => true,
+            GetMut | GetOptionMut | GetIterMut => true,
+            Update | Set | SetTry | SetWith | Map | Take | Replace | Swap => false,
+            Collection | Cell | RefCell | Lock | Atomic | Shared | Pin | Unpin => false,
+        }
+    }
+
+    /// Modes generating methods of the same name, of which a field may only choose one.
+    fn rivals(self) -> &'static [GenMode] {
+        match self {
+            Get | GetDeref | GetOption => &[Get, GetDeref, GetOption],
+            GetMut | GetOptionMut => &[GetMut, GetOptionMut],
+            _ => &[],
         }
     }
 
-    fn is_get(self) -> bool {
+    /// Name of the method generated for the single field of a unary struct, which is
+    /// followed by the field's position in tuple structs with several fields.
+    fn unary_name(self) -> &'static str {
         match self {
-            Get | GetClone | GetCopy | GetMut => true,
-            Set | SetWith => false,
+            Get | GetClone | GetCopy | GetDeref | GetOption => "get",
+            GetMut | GetOptionMut => "get_mut",
+            GetIter => "iter",
+            GetIterMut => "iter_mut",
+            Set => "set",
+            SetTry => "try_set",
+            SetWith => "set_with",
+            Update => "update",
+            Map => "map",
+            Take => "take",
+            Replace => "replace",
+            Swap => "swap",
+            // Collection methods are named after the operation alone.
+            Collection => "collection",
+            // Interior accessors are named after the value they read, so as not to clash
+            // with the `get` and `set` of `Getters` and `Setters`.
+            Cell => "value",
+            RefCell => "borrow",
+            Lock => "lock",
+            Atomic => "value",
+            Shared => "value",
+            // `get_mut` would be shadowed by `Pin::get_mut`.
+            Pin => "pin_mut",
+            Unpin => "unpin_mut",
         }
     }
 }
 
-// Helper function to extract string from Expr
-fn expr_to_string(expr: &Expr) -> Option<String> {
-    if let Expr::Lit(expr_lit) = expr {
-        if let Lit::Str(s) = &expr_lit.lit {
-            Some(s.value())
-        } else {
-            None
+/// Memory orderings which may be given to `atomic(ordering = "...")`.
+const ORDERINGS: &[&str] = &["Relaxed", "Release", "Acquire", "AcqRel", "SeqCst"];
+
+// Helper function to parse the options of a list attribute, e.g. `get(vis = "pub", ...)`
+fn list_options(attr: Option<&Meta>) -> Vec<Meta> {
+    let Some(Meta::List(list)) = attr else {
+        return Vec::new();
+    };
+
+    match list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated) {
+        Ok(options) => options.into_iter().collect(),
+        Err(e) => abort!(list.span(), "Failed to parse options: {}", e),
+    }
+}
+
+// Helper function to check whether an attribute contains an option word, given
+// either in its string (`"pub with_prefix"`) or in its list (`(with_prefix)`)
+fn has_option_word(attr: Option<&Meta>, word: &str) -> bool {
+    match attr {
+        Some(Meta::NameValue(MetaNameValue { value, .. })) => {
+            attr_str(value).is_some_and(|(_, words)| words.iter().any(|v| v == word))
         }
-    } else {
-        None
+        Some(Meta::List(_)) => list_options(attr)
+            .iter()
+            .any(|option| matches!(option, Meta::Path(path) if path.is_ident(word))),
+        _ => false,
+    }
+}
+
+// Helper function to get the options a method name is derived from
+fn naming_options(attr: &Meta) -> (Option<String>, Option<String>, Option<String>, bool) {
+    let value = |key| option_value(Some(attr), key).as_ref().map(LitStr::value);
+    (
+        value("name"),
+        value("prefix"),
+        value("suffix"),
+        has_option_word(Some(attr), "with_prefix") || has_option_word(Some(attr), "prefix"),
+    )
+}
+
+// Helper function to get the string literal of a `key = "value"` option of a list attribute
+fn option_value(attr: Option<&Meta>, key: &str) -> Option<LitStr> {
+    list_options(attr)
+        .into_iter()
+        .find_map(|option| match option {
+            Meta::NameValue(MetaNameValue { path, value, .. }) if path.is_ident(key) => match value
+            {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(lit), ..
+                }) => Some(lit),
+                _ => abort!(value.span(), "Expected a string value for `{}`", key),
+            },
+            _ => None,
+        })
+}
+
+// Helper function to parse the value of a `key = "value"` option as `T`
+fn parse_option_value<T: syn::parse::Parse>(attr: Option<&Meta>, key: &str) -> Option<T> {
+    let lit = option_value(attr, key)?;
+    match lit.parse() {
+        Ok(parsed) => Some(parsed),
+        Err(e) => abort!(lit.span(), "Invalid `{}` found: {}", key, e),
+    }
+}
+
+// Helper function to get the generic type arguments of a type named `name`,
+// e.g. `[T, E]` for `Result<T, E>`
+fn type_args<'a>(ty: &'a Type, name: &str) -> Option<Vec<&'a Type>> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != name {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+
+    Some(
+        args.args
+            .iter()
+            .filter_map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+    )
+}
+
+// Helper function to name a method accompanying another, by surrounding the `base`
+// of its name with the given prefix and suffix instead of the mode's
+fn companion_name(base: &str, rename_all: Option<&LitStr>, prefix: &str, suffix: &str) -> Ident {
+    Ident::new(
+        &rename(&format!("{prefix}{base}{suffix}"), rename_all),
+        Span::call_site(),
+    )
+}
+
+// Helper function to name a method of a tuple struct after the operation, followed by
+// the field's position unless the struct is unary, e.g. `set` or `set_1`
+fn positional_name(name: &str, position: &str) -> Ident {
+    Ident::new(&format!("{name}{position}"), Span::call_site())
+}
+
+/// Whether the methods of the struct or of any of its fields are renamed with `rename_all`.
+pub fn has_rename_all(ast: &DeriveInput) -> bool {
+    let Data::Struct(DataStruct { fields, .. }) = &ast.data else {
+        return false;
+    };
+
+    parse_rename_all(&ast.attrs).is_some()
+        || fields
+            .iter()
+            .any(|field| parse_rename_all(&field.attrs).is_some())
+}
+
+/// Converts a snake_case method name to the case given with `rename_all`, following serde's
+/// conversion of field names.
+fn rename(name: &str, rename_all: Option<&LitStr>) -> String {
+    let Some(rename_all) = rename_all else {
+        return name.to_string();
+    };
+    let pascal_case = || {
+        name.split('_')
+            .map(|word| {
+                let mut chars = word.chars();
+                chars.next().map_or_else(String::new, |first| {
+                    first.to_uppercase().chain(chars).collect()
+                })
+            })
+            .collect::<String>()
+    };
+
+    match rename_all.value().as_str() {
+        // Hyphens cannot appear in identifiers, so kebab-case falls back to snake_case.
+        "lowercase" | "snake_case" | "kebab-case" => name.to_string(),
+        "UPPERCASE" | "SCREAMING_SNAKE_CASE" | "SCREAMING-KEBAB-CASE" => name.to_ascii_uppercase(),
+        "PascalCase" => pascal_case(),
+        "camelCase" => {
+            let pascal_case = pascal_case();
+            let mut chars = pascal_case.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_lowercase().chain(chars).collect()
+            })
+        }
+        _ => abort!(
+            rename_all.span(),
+            "`rename_all` must be one of \"lowercase\", \"UPPERCASE\", \"PascalCase\", \
+             \"camelCase\", \"snake_case\", \"SCREAMING_SNAKE_CASE\", \"kebab-case\" or \
+             \"SCREAMING-KEBAB-CASE\""
+        ),
     }
 }
 
@@ -81,18 +353,250 @@ fn parse_vis_str(s: &str, span: proc_macro2::Span) -> Visibility {
 // Helper function to parse visibility attribute
 pub fn parse_visibility(attr: Option<&Meta>, meta_name: &str) -> Option<Visibility> {
     let meta = attr?;
-    let Meta::NameValue(MetaNameValue { value, path, .. }) = meta else {
+    if !meta.path().is_ident(meta_name) {
         return None;
+    }
+
+    let Meta::NameValue(MetaNameValue { value, .. }) = meta else {
+        return option_value(attr, "vis").map(|lit| parse_vis_str(&lit.value(), lit.span()));
     };
 
-    if !path.is_ident(meta_name) {
+    attr_str(value).map(|(vis, _)| vis)
+}
+
+// Helper function to split an attribute string, e.g. `"pub(in crate::a) with_prefix"`,
+// into its visibility and its option words
+fn attr_str(value: &Expr) -> Option<(Visibility, Vec<Ident>)> {
+    let Expr::Lit(ExprLit {
+        lit: Lit::Str(lit), ..
+    }) = value
+    else {
         return None;
+    };
+
+    let parser = |input: ParseStream| {
+        let mut vis = Visibility::Inherited;
+        let mut words = Vec::new();
+        while !input.is_empty() {
+            if input.peek(Token![pub]) && matches!(vis, Visibility::Inherited) {
+                vis = input.parse()?;
+            } else {
+                words.push(input.call(Ident::parse_any)?);
+            }
+        }
+        Ok((vis, words))
+    };
+
+    match lit.parse_with(parser) {
+        Ok(parsed) => Some(parsed),
+        Err(e) => abort!(lit.span(), "Invalid visibility found: {}", e),
+    }
+}
+
+/// Options which may be given as flags, e.g. `get(with_prefix)` or `get = "pub with_prefix"`,
+/// and as `key = "value"` pairs in the list form, e.g. `get(vis = "pub")`.
+fn mode_options(mode: GenMode) -> (Vec<&'static str>, Vec<&'static str>) {
+    let mut flags = vec!["inline"];
+    let mut values = vec!["vis", "name", "inline"];
+    if mode.is_get() {
+        flags.extend(["with_prefix", "prefix"]);
+    }
+    // Modes generating several methods after the field, e.g. `read_field` and `write_field`,
+    // have no single prefix or suffix to replace.
+    if !matches!(mode, Collection | Cell | RefCell | Lock | Atomic | Shared) {
+        values.extend(["prefix", "suffix"]);
+    }
+
+    let (mode_flags, mode_values): (&[&str], &[&str]) = match mode {
+        Get => (&[], &["as_ref", "borrow", "into", "with", "returns"]),
+        GetOption => (&["or_default", "or"], &[]),
+        Set | SetWith => (&["into", "strip_option"], &["validate", "error"]),
+        RefCell => (&[], &["policy"]),
+        Lock => (&[], &["poison"]),
+        Atomic => (&["fetch_add", "swap"], &["ordering"]),
+        Shared => (&["make_mut"], &[]),
+        _ => (&[], &[]),
+    };
+    flags.extend(mode_flags);
+    values.extend(mode_values);
+
+    (flags, values)
+}
+
+// Helper function to point at a word of an attribute string, where the compiler allows
+// spans within a literal, or else at the whole string
+fn word_span(value: &Expr, word: &Ident) -> Span {
+    let Expr::Lit(ExprLit {
+        lit: Lit::Str(lit), ..
+    }) = value
+    else {
+        return value.span();
+    };
+    let text = lit.token().to_string();
+    let word = word.to_string();
+    let is_boundary = |c: Option<char>| !c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+
+    text.match_indices(&word)
+        .find(|(start, _)| {
+            is_boundary(text[..*start].chars().next_back())
+                && is_boundary(text[start + word.len()..].chars().next())
+        })
+        .and_then(|(start, _)| lit.token().subspan(start..start + word.len()))
+        .unwrap_or_else(|| lit.span())
+}
+
+// Helper function to reject the options of an attribute which `mode` does not know
+fn validate_options(attr: Option<&Meta>, mode: GenMode) {
+    let (flags, values) = mode_options(mode);
+
+    // `with_prefix` has always been accepted, and ignored, by modes other than getters, so
+    // that one string can be shared by a struct-level attribute.
+    if let Some(Meta::NameValue(MetaNameValue { value, .. })) = attr
+        && let Some((_, words)) = attr_str(value)
+        && let Some(word) = words
+            .iter()
+            .find(|word| *word != "with_prefix" && !flags.iter().any(|flag| *word == flag))
+    {
+        abort!(
+            word_span(value, word),
+            "unknown option `{}` for `{}`",
+            word,
+            mode.name()
+        )
+    }
+
+    for option in list_options(attr) {
+        let path = option.path();
+        let known = |names: &[&str]| names.iter().any(|name| path.is_ident(name));
+        match &option {
+            Meta::Path(_) if known(&flags) => {}
+            Meta::NameValue(_) if known(&values) => {}
+            Meta::Path(_) if known(&values) => {
+                abort!(path.span(), "`{}` requires a value", path.to_token_stream())
+            }
+            Meta::NameValue(_) if known(&flags) => {
+                abort!(
+                    path.span(),
+                    "`{}` does not take a value",
+                    path.to_token_stream()
+                )
+            }
+            _ => abort!(
+                path.span(),
+                "unknown option `{}` for `{}`",
+                path.to_token_stream(),
+                mode.name()
+            ),
+        }
+    }
+}
+
+/// Generated methods are `#[inline(always)]`, unless given `inline` for a plain `#[inline]`
+/// or `inline = "never"`.
+fn inline_attr(attr: Option<&Meta>) -> TokenStream2 {
+    let inline = option_value(attr, "inline");
+    match inline.as_ref().map(LitStr::value).as_deref() {
+        None if has_option_word(attr, "inline") => quote! { #[inline] },
+        None | Some("always") => quote! { #[inline(always)] },
+        Some("never") => quote! { #[inline(never)] },
+        Some(_) => abort!(inline.span(), "`inline` must be \"always\" or \"never\""),
+    }
+}
+
+/// Getters may return another type than the field's, by passing the field through
+/// `AsRef`, `Borrow`, `Into` or a user supplied function.
+fn get_conversion(attr: Option<&Meta>, ty: &Type, member: &Member) -> (TokenStream2, TokenStream2) {
+    let as_ref = parse_option_value::<Type>(attr, "as_ref");
+    let borrow = parse_option_value::<Type>(attr, "borrow");
+    let into = parse_option_value::<Type>(attr, "into");
+    let with = parse_option_value::<syn::Path>(attr, "with");
+    let returns = parse_option_value::<Type>(attr, "returns");
+
+    match (as_ref, borrow, into, with, returns) {
+        (None, None, None, None, None) => (quote! { &#ty }, quote! { &self.#member }),
+        (Some(target), None, None, None, None) => (
+            quote! { &#target },
+            quote! { ::core::convert::AsRef::<#target>::as_ref(&self.#member) },
+        ),
+        (None, Some(target), None, None, None) => (
+            quote! { &#target },
+            quote! { ::core::borrow::Borrow::<#target>::borrow(&self.#member) },
+        ),
+        (None, None, Some(target), None, None) => (
+            quote! { #target },
+            quote! { ::core::convert::Into::into(::core::clone::Clone::clone(&self.#member)) },
+        ),
+        (None, None, None, Some(with), Some(returns)) => {
+            (quote! { #returns }, quote! { #with(&self.#member) })
+        }
+        (None, None, None, Some(with), None) => {
+            abort!(
+                with.span(),
+                "`with` requires the `returns` type to be given"
+            )
+        }
+        (None, None, None, None, Some(returns)) => {
+            abort!(
+                returns.span(),
+                "`returns` requires a `with` function to be given"
+            )
+        }
+        _ => abort!(
+            attr.span(),
+            "only one of `as_ref`, `borrow`, `into` or `with` may be given"
+        ),
+    }
+}
+
+/// Setters with the `into` option accept anything convertible into the field's type,
+/// while those with `strip_option` take the `T` of an `Option<T>` field.
+fn setter_value(attr: Option<&Meta>, ty: &Type) -> (TokenStream2, TokenStream2) {
+    let ty = if has_option_word(attr, "strip_option") {
+        match type_args(ty, "Option").as_deref() {
+            Some([inner]) => *inner,
+            _ => abort!(
+                ty.span(),
+                "`strip_option` is only supported on `Option<T>` fields"
+            ),
+        }
+    } else {
+        ty
+    };
+
+    if has_option_word(attr, "into") {
+        (
+            quote! { impl ::core::convert::Into<#ty> },
+            quote! { ::core::convert::Into::into(val) },
+        )
+    } else {
+        (quote! { #ty }, quote! { val })
+    }
+}
+
+/// Stores the setter's value in the field, wrapping it in `Some` with `strip_option`.
+fn stored_value(attr: Option<&Meta>, val: TokenStream2) -> TokenStream2 {
+    if has_option_word(attr, "strip_option") {
+        quote! { ::core::option::Option::Some(#val) }
+    } else {
+        val
     }
+}
 
-    let value_str = expr_to_string(value)?;
-    let vis_str = value_str.split(' ').find(|v| *v != "with_prefix")?;
+/// Setters with the `validate` option check the value with a `fn(&T) -> Result<(), E>`
+/// before storing it, and return its `error` type on failure.
+fn setter_validation(attr: Option<&Meta>) -> Option<(syn::Path, Type)> {
+    let validate = parse_option_value::<syn::Path>(attr, "validate");
+    let error = parse_option_value::<Type>(attr, "error");
 
-    Some(parse_vis_str(vis_str, value.span()))
+    match (validate, error) {
+        (Some(validate), Some(error)) => Some((validate, error)),
+        (Some(validate), None) => abort!(
+            validate.span(),
+            "`validate` requires the validator's `error` type to be given"
+        ),
+        (None, Some(error)) => abort!(error.span(), "`error` requires a `validate` function"),
+        (None, None) => None,
+    }
 }
 
 /// Some users want legacy/compatibility.
@@ -100,12 +604,7 @@ pub fn parse_visibility(attr: Option<&Meta>, meta_name: &str) -> Option<Visibili
 fn has_prefix_attr(f: &Field, params: &GenParams) -> bool {
     // helper function to check if meta has `with_prefix` attribute
     let meta_has_prefix = |meta: &Meta| -> bool {
-        if let Meta::NameValue(name_value) = meta {
-            if let Some(s) = expr_to_string(&name_value.value) {
-                return s.split(" ").any(|v| v == "with_prefix");
-            }
-        }
-        false
+        has_option_word(Some(meta), "with_prefix") || has_option_word(Some(meta), "prefix")
     };
 
     let field_attr_has_prefix = f
@@ -113,10 +612,9 @@ fn has_prefix_attr(f: &Field, params: &GenParams) -> bool {
         .iter()
         .filter_map(|attr| parse_attr(attr, params.mode))
         .find(|meta| {
-            meta.path().is_ident("get")
-                || meta.path().is_ident("get_clone")
-                || meta.path().is_ident("get_copy")
-                || meta.path().is_ident("get_mut")
+            GenMode::ALL
+                .iter()
+                .any(|mode| mode.is_get() && meta.path().is_ident(mode.name()))
         })
         .as_ref()
         .is_some_and(meta_has_prefix);
@@ -126,186 +624,1202 @@ fn has_prefix_attr(f: &Field, params: &GenParams) -> bool {
     field_attr_has_prefix || global_attr_has_prefix
 }
 
+/// Methods may be given a name of their own with `name = "..."`, on fields only since the
+/// methods of every field would otherwise collide.
+fn custom_name(field: &Field, params: &GenParams) -> Option<Ident> {
+    if let Some(name) = option_value(params.global_attr.as_ref(), "name") {
+        abort!(name.span(), "`name` can only be given on fields")
+    }
+
+    parse_option_value(own_attr(field, params.mode).as_ref(), "name")
+}
+
+// Helper function to get the string of a `key = "value"` option given on the field,
+// or else on the struct
+fn naming_option(field: &Field, params: &GenParams, key: &str) -> Option<LitStr> {
+    option_value(own_attr(field, params.mode).as_ref(), key)
+        .or_else(|| option_value(params.global_attr.as_ref(), key))
+}
+
 pub fn implement(field: &Field, params: &GenParams) -> TokenStream2 {
     let field_name = field
         .ident
         .clone()
         .unwrap_or_else(|| abort!(field.span(), "Expected the field to have a name"));
 
-    let fn_name = if !has_prefix_attr(field, params)
-        && (params.mode.is_get())
-        && params.mode.suffix().is_empty()
-        && field_name.to_string().starts_with("r#")
-    {
-        field_name.clone()
+    // A custom `prefix` replaces the mode's own, or `with_prefix` for getters.
+    let prefix = naming_option(field, params, "prefix");
+    let suffix = naming_option(field, params, "suffix");
+    let (mode_prefix, mode_suffix) = mode_affixes(field, params);
+
+    // A field's own `rename_all` overrides the struct's, while `name` is kept as is.
+    let rename_all = parse_rename_all(&field.attrs).or_else(|| params.rename_all.clone());
+    let mut base = None;
+
+    let fn_name = if let Some(name) = custom_name(field, params) {
+        name
     } else {
-        Ident::new(
-            &format!(
-                "{}{}{}{}",
-                if has_prefix_attr(field, params) && (params.mode.is_get()) {
+        let prefix_str = prefix.as_ref().map_or_else(
+            || {
+                let with_prefix = if has_prefix_attr(field, params) && params.mode.is_get() {
                     "get_"
                 } else {
                     ""
-                },
-                params.mode.prefix(),
-                field_name.unraw(),
-                params.mode.suffix()
-            ),
-            Span::call_site(),
+                };
+                format!("{}{}", with_prefix, params.mode.prefix())
+            },
+            LitStr::value,
+        );
+
+        let name = format!("{}{}{}", prefix_str, field_name.unraw(), mode_suffix);
+        base = Some(format!(
+            "{}{}",
+            prefix_str
+                .strip_prefix(mode_prefix.as_str())
+                .unwrap_or(&prefix_str),
+            field_name.unraw()
+        ));
+        let name = rename(&name, rename_all.as_ref());
+
+        if field_name.to_string().starts_with("r#") && field_name.unraw() == name {
+            field_name.clone()
+        } else {
+            match syn::parse_str::<Ident>(&name) {
+                Ok(name) => Ident::new(&name.to_string(), Span::call_site()),
+                Err(_) => abort!(
+                    prefix.as_ref().or(suffix.as_ref()).span(),
+                    "`{}` is not a valid method name",
+                    name
+                ),
+            }
+        }
+    };
+
+    let base = base.unwrap_or_else(|| custom_base(&fn_name, &mode_prefix, &mode_suffix));
+
+    implement_member(
+        field,
+        params,
+        &fn_name,
+        &base,
+        rename_all.as_ref(),
+        None,
+        &Member::Named(field_name),
+    )
+}
+
+pub fn implement_for_unnamed(field: &Field, params: &GenParams) -> TokenStream2 {
+    reject_renaming(field, params);
+    let fn_name = custom_name(field, params)
+        .unwrap_or_else(|| Ident::new(params.mode.unary_name(), Span::call_site()));
+    let base = fn_name.unraw().to_string();
+    implement_member(
+        field,
+        params,
+        &fn_name,
+        &base,
+        None,
+        Some(""),
+        &Member::Unnamed(0.into()),
+    )
+}
+
+/// Fields of tuple structs with several fields are accessed through the methods of a unary
+/// struct suffixed with their position, e.g. `get_0` and `value_1`, unless they are given a
+/// `name`.
+pub fn implement_for_position(field: &Field, index: usize, params: &GenParams) -> TokenStream2 {
+    let member = Member::Unnamed(index.into());
+    reject_renaming(field, params);
+
+    if let Some(fn_name) = custom_name(field, params) {
+        // Named positional fields are accompanied by methods named like those of named fields.
+        let (mode_prefix, mode_suffix) = mode_affixes(field, params);
+        let base = custom_base(&fn_name, &mode_prefix, &mode_suffix);
+        implement_member(field, params, &fn_name, &base, None, None, &member)
+    } else {
+        let position = format!("_{index}");
+        let base = params.mode.unary_name();
+        let fn_name = positional_name(base, &position);
+        implement_member(
+            field,
+            params,
+            &fn_name,
+            base,
+            None,
+            Some(&position),
+            &member,
         )
+    }
+}
+
+// Helper function to reject the naming options of tuple structs, whose methods are named
+// after the mode rather than the field. Only fields given a `name` may set the affixes
+// stripped from it to name companion methods.
+fn reject_renaming(field: &Field, params: &GenParams) {
+    let own = own_attr(field, params.mode);
+    let named = option_value(own.as_ref(), "name").is_some();
+
+    for key in ["prefix", "suffix"] {
+        let own = option_value(own.as_ref(), key).filter(|_| !named);
+        if let Some(value) = option_value(params.global_attr.as_ref(), key).or(own) {
+            abort!(
+                value.span(),
+                "`{}` is only supported on tuple struct fields given a `name`",
+                key
+            )
+        }
+    }
+    if let Some(rename_all) = params
+        .rename_all
+        .clone()
+        .or_else(|| parse_rename_all(&field.attrs))
+    {
+        abort!(
+            rename_all.span(),
+            "`rename_all` is not supported on tuple structs"
+        )
+    }
+}
+
+// Helper function to get the prefix and suffix of the mode, as overridden on the field or
+// the struct. A custom `prefix` replaces `with_prefix` rather than the prefix of getters.
+fn mode_affixes(field: &Field, params: &GenParams) -> (String, String) {
+    let mode_prefix = match naming_option(field, params, "prefix") {
+        Some(prefix) if !params.mode.is_get() => prefix.value(),
+        _ => params.mode.prefix().to_string(),
+    };
+    let mode_suffix = naming_option(field, params, "suffix")
+        .as_ref()
+        .map_or_else(|| params.mode.suffix().to_string(), LitStr::value);
+    (mode_prefix, mode_suffix)
+}
+
+// Companion methods are named after what remains of a custom name without the mode's
+// prefix and suffix.
+fn custom_base(fn_name: &Ident, mode_prefix: &str, mode_suffix: &str) -> String {
+    let fn_name = fn_name.unraw().to_string();
+    let base = fn_name.strip_prefix(mode_prefix).unwrap_or(&fn_name);
+    base.strip_suffix(mode_suffix).unwrap_or(base).to_string()
+}
+
+// Helper function to get the attribute given for `mode` on the field itself
+fn own_attr(field: &Field, mode: GenMode) -> Option<Meta> {
+    field
+        .attrs
+        .iter()
+        .filter_map(|v| parse_attr(v, mode))
+        .next_back()
+}
+
+/// Structural pinning is only sound when the struct is `Unpin` only if its pinned fields are,
+/// has no `Drop` implementation moving them out, and is not `#[repr(packed)]`.
+pub fn pin_guards(ast: &DeriveInput, params: &GenParams) -> TokenStream2 {
+    let name = &ast.ident;
+
+    if let Some(repr) = ast.attrs.iter().find(|attr| attr.path().is_ident("repr")) {
+        let mut packed = false;
+        let _ = repr.parse_nested_meta(|meta| {
+            packed |= meta.path.is_ident("packed");
+            if meta.input.peek(syn::token::Paren) {
+                let _content;
+                syn::parenthesized!(_content in meta.input);
+            }
+            Ok(())
+        });
+        if packed {
+            abort!(
+                repr.span(),
+                "`PinProjections` cannot be derived for `#[repr(packed)]` structs"
+            )
+        }
+    }
+
+    let Data::Struct(DataStruct { fields, .. }) = &ast.data else {
+        return quote! {};
     };
+    let pinned = fields
+        .iter()
+        .filter(|field| {
+            own_attr(field, Unpin).is_none()
+                && own_attr(field, Pin)
+                    .or_else(|| params.global_attr.clone())
+                    .is_some_and(|meta| !meta.path().is_ident("skip"))
+        })
+        .map(|field| &field.ty);
+
+    // The struct's generics, preceded by a lifetime keeping the `Unpin` bound from being
+    // trivial, e.g. for `PhantomPinned` fields.
+    let mut origin_generics = ast.generics.clone();
+    origin_generics
+        .params
+        .insert(0, GenericParam::Lifetime(parse_quote! { 'getset }));
+    let (_, origin_ty_generics, _) = origin_generics.split_for_impl();
+    let origin_where_clause = &origin_generics.where_clause;
+    let (_, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let mut unpin_generics = origin_generics.clone();
+    unpin_generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote! { __GetsetOrigin #origin_ty_generics: ::core::marker::Unpin });
+    let (unpin_impl_generics, _, unpin_where_clause) = unpin_generics.split_for_impl();
+    let (impl_generics, _, _) = ast.generics.split_for_impl();
+
+    quote! {
+        const _: () = {
+            #[allow(dead_code, non_snake_case)]
+            struct __GetsetOrigin #origin_generics #origin_where_clause {
+                __getset_phantom: ::core::marker::PhantomData<(&'getset (), fn() -> #name #ty_generics)>,
+                __getset_pinned: (#(#pinned,)*),
+            }
+
+            // Conflicts with any other `Unpin` implementation of the struct.
+            impl #unpin_impl_generics ::core::marker::Unpin for #name #ty_generics
+                #unpin_where_clause
+            {
+            }
+
+            // Conflicts with any `Drop` implementation of the struct.
+            trait __GetsetMustNotImplDrop {}
+            #[allow(drop_bounds)]
+            impl<T: ::core::ops::Drop> __GetsetMustNotImplDrop for T {}
+            impl #impl_generics __GetsetMustNotImplDrop for #name #ty_generics #where_clause {}
+        };
+    }
+}
+
+/// Generates the method for `params.mode` accessing `field` through `self.#member`.
+fn implement_member(
+    field: &Field,
+    params: &GenParams,
+    fn_name: &Ident,
+    base: &str,
+    rename_all: Option<&LitStr>,
+    position: Option<&str>,
+    member: &Member,
+) -> TokenStream2 {
     let ty = field.ty.clone();
 
-    let doc = field.attrs.iter().filter(|v| v.meta.path().is_ident("doc"));
+    let doc: Vec<_> = field
+        .attrs
+        .iter()
+        .filter(|v| v.meta.path().is_ident("doc"))
+        .collect();
+
+    // A field may only choose rival modes of its own which name their methods differently.
+    let chosen =
+        |mode: GenMode| own_attr(field, mode).filter(|meta| meta.path().is_ident(mode.name()));
+    if let Some(own) = chosen(params.mode) {
+        for &rival in params.mode.rivals() {
+            if let Some(other) = chosen(rival).filter(|_| rival != params.mode)
+                && naming_options(&own) == naming_options(&other)
+            {
+                abort!(
+                    other.span(),
+                    "`{}` and `{}` generate the same method, give one of them a `name`",
+                    params.mode.name(),
+                    rival.name()
+                )
+            }
+        }
+    }
 
+    // A field choosing a rival mode of its own is left out of the struct's attribute.
+    let rivaled = params.mode.rivals().iter().any(|&rival| {
+        rival != params.mode
+            && own_attr(field, rival).is_some_and(|meta| meta.path().is_ident(rival.name()))
+    });
     let attr = field
         .attrs
         .iter()
         .filter_map(|v| parse_attr(v, params.mode))
         .next_back()
-        .or_else(|| params.global_attr.clone());
+        .or_else(|| (!rivaled).then(|| params.global_attr.clone()).flatten());
+
+    validate_options(own_attr(field, params.mode).as_ref(), params.mode);
+    validate_options(params.global_attr.as_ref(), params.mode);
 
     let visibility = parse_visibility(attr.as_ref(), params.mode.name());
+    let inline = inline_attr(attr.as_ref());
     match attr {
         // Generate nothing for skipped field
         Some(meta) if meta.path().is_ident("skip") => quote! {},
         Some(_) => match params.mode {
             Get => {
+                let (ret, body) = get_conversion(attr.as_ref(), &ty, member);
                 quote! {
                     #(#doc)*
-                    #[inline(always)]
-                    #visibility fn #fn_name(&self) -> &#ty {
-                        &self.#field_name
+                    #inline
+                    #visibility fn #fn_name(&self) -> #ret {
+                        #body
                     }
                 }
             }
             GetClone => {
                 quote! {
                     #(#doc)*
-                    #[inline(always)]
+                    #inline
                     #visibility fn #fn_name(&self) -> #ty {
-                        self.#field_name.clone()
+                        self.#member.clone()
                     }
                 }
             }
             GetCopy => {
                 quote! {
                     #(#doc)*
-                    #[inline(always)]
+                    #inline
                     #visibility fn #fn_name(&self) -> #ty {
-                        self.#field_name
+                        self.#member
                     }
                 }
             }
-            Set => {
+            GetDeref => {
                 quote! {
                     #(#doc)*
-                    #[inline(always)]
-                    #visibility fn #fn_name(&mut self, val: #ty) -> &mut Self {
-                        self.#field_name = val;
-                        self
+                    #inline
+                    #visibility fn #fn_name(&self) -> &<#ty as ::core::ops::Deref>::Target
+                    where
+                        for<'getset> #ty: ::core::ops::Deref,
+                    {
+                        ::core::ops::Deref::deref(&self.#member)
                     }
                 }
             }
-            GetMut => {
+            GetOption => {
+                let (inner, ret, or_default_body) = if let Some([inner]) =
+                    type_args(&ty, "Option").as_deref()
+                {
+                    (
+                        *inner,
+                        quote! { ::core::option::Option<&#inner> },
+                        quote! { self.#member.as_ref().cloned().unwrap_or_default() },
+                    )
+                } else if let Some([inner, error]) = type_args(&ty, "Result").as_deref() {
+                    (
+                        *inner,
+                        quote! { ::core::result::Result<&#inner, &#error> },
+                        quote! { self.#member.as_ref().ok().cloned().unwrap_or_default() },
+                    )
+                } else {
+                    abort!(
+                        ty.span(),
+                        "`get_option` is only supported on `Option<T>` and `Result<T, E>` fields"
+                    )
+                };
+
+                // Positional fields name their helpers `get_or_default_0` rather than
+                // `get_0_or_default`.
+                let name = |suffix: &str| match position {
+                    None => companion_name(base, rename_all, "", suffix),
+                    Some(position) => positional_name(&format!("{base}{suffix}"), position),
+                };
+                let or_default = has_option_word(attr.as_ref(), "or_default").then(|| {
+                    let or_default_name = name("_or_default");
+                    quote! {
+                        #(#doc)*
+                        #inline
+                        #visibility fn #or_default_name(&self) -> #inner
+                        where
+                            // Binding the bound keeps it from being a hard error on concrete
+                            // types, so that the method only exists when it holds.
+                            for<'getset> #inner: ::core::clone::Clone + ::core::default::Default,
+                        {
+                            #or_default_body
+                        }
+                    }
+                });
+                let or = has_option_word(attr.as_ref(), "or").then(|| {
+                    let or_name = name("_or");
+                    quote! {
+                        #(#doc)*
+                        #inline
+                        #visibility fn #or_name<'getset>(
+                            &'getset self,
+                            default: &'getset #inner,
+                        ) -> &'getset #inner {
+                            self.#member.as_ref().unwrap_or(default)
+                        }
+                    }
+                });
+
                 quote! {
                     #(#doc)*
-                    #[inline(always)]
-                    #visibility fn #fn_name(&mut self) -> &mut #ty {
-                        &mut self.#field_name
+                    #inline
+                    #visibility fn #fn_name(&self) -> #ret {
+                        self.#member.as_ref()
                     }
+                    #or_default
+                    #or
                 }
             }
-            SetWith => {
+            GetIter => {
                 quote! {
                     #(#doc)*
-                    #[inline(always)]
-                    #visibility fn #fn_name(mut self, val: #ty) -> Self {
-                        self.#field_name = val;
-                        self
+                    #inline
+                    #visibility fn #fn_name<'getset>(
+                        &'getset self,
+                    ) -> impl ::core::iter::Iterator<
+                        Item = <&'getset #ty as ::core::iter::IntoIterator>::Item,
+                    > + 'getset
+                    where
+                        &'getset #ty: ::core::iter::IntoIterator,
+                    {
+                        ::core::iter::IntoIterator::into_iter(&self.#member)
                     }
                 }
             }
-        },
-        None => quote! {},
-    }
-}
+            Set => {
+                let (val_ty, val) = setter_value(attr.as_ref(), &ty);
+                let setter = if let Some((validate, error)) = setter_validation(attr.as_ref()) {
+                    let stored = stored_value(attr.as_ref(), quote! { val });
+                    quote! {
+                        #(#doc)*
+                        #inline
+                        #visibility fn #fn_name(
+                            &mut self,
+                            val: #val_ty,
+                        ) -> ::core::result::Result<&mut Self, #error> {
+                            let val = #val;
+                            #validate(&val)?;
+                            self.#member = #stored;
+                            ::core::result::Result::Ok(self)
+                        }
+                    }
+                } else {
+                    let stored = stored_value(attr.as_ref(), val);
+                    quote! {
+                        #(#doc)*
+                        #inline
+                        #visibility fn #fn_name(&mut self, val: #val_ty) -> &mut Self {
+                            self.#member = #stored;
+                            self
+                        }
+                    }
+                };
+                // Setters of stripped options are accompanied by one resetting the field
+                // to `None`.
+                let clear = has_option_word(attr.as_ref(), "strip_option").then(|| {
+                    let clear_name = match position {
+                        None => companion_name(base, rename_all, "clear_", ""),
+                        Some(position) => positional_name("clear", position),
+                    };
+                    quote! {
+                        #(#doc)*
+                        #inline
+                        #visibility fn #clear_name(&mut self) -> &mut Self {
+                            self.#member = ::core::option::Option::None;
+                            self
+                        }
+                    }
+                });
 
-pub fn implement_for_unnamed(field: &Field, params: &GenParams) -> TokenStream2 {
-    let doc = field.attrs.iter().filter(|v| v.meta.path().is_ident("doc"));
-    let attr = field
-        .attrs
-        .iter()
-        .filter_map(|v| parse_attr(v, params.mode))
-        .next_back()
-        .or_else(|| params.global_attr.clone());
-    let ty = field.ty.clone();
-    let visibility = parse_visibility(attr.as_ref(), params.mode.name());
+                quote! {
+                    #setter
+                    #clear
+                }
+            }
+            SetTry => {
+                // `try_set_field` is accompanied by `try_with_field`, while the
+                // unary `try_set` is accompanied by `try_set_with`.
+                let with_name = match position {
+                    None => companion_name(base, rename_all, "try_with_", ""),
+                    Some(position) => positional_name("try_set_with", position),
+                };
 
-    match attr {
-        // Generate nothing for skipped field
-        Some(meta) if meta.path().is_ident("skip") => quote! {},
-        Some(_) => match params.mode {
-            Get => {
-                let fn_name = Ident::new("get", Span::call_site());
                 quote! {
                     #(#doc)*
-                    #[inline(always)]
-                    #visibility fn #fn_name(&self) -> &#ty {
-                        &self.0
+                    #inline
+                    #visibility fn #fn_name<__V: ::core::convert::TryInto<#ty>>(
+                        &mut self,
+                        val: __V,
+                    ) -> ::core::result::Result<&mut Self, __V::Error> {
+                        self.#member = ::core::convert::TryInto::try_into(val)?;
+                        ::core::result::Result::Ok(self)
+                    }
+                    #(#doc)*
+                    #inline
+                    #visibility fn #with_name<__V: ::core::convert::TryInto<#ty>>(
+                        mut self,
+                        val: __V,
+                    ) -> ::core::result::Result<Self, __V::Error> {
+                        self.#member = ::core::convert::TryInto::try_into(val)?;
+                        ::core::result::Result::Ok(self)
                     }
                 }
             }
-            GetClone => {
-                let fn_name = Ident::new("get", Span::call_site());
+            Update => {
                 quote! {
                     #(#doc)*
-                    #[inline(always)]
-                    #visibility fn #fn_name(&self) -> #ty {
-                        self.0.clone()
+                    #inline
+                    #visibility fn #fn_name(
+                        &mut self,
+                        f: impl ::core::ops::FnOnce(&mut #ty),
+                    ) -> &mut Self {
+                        f(&mut self.#member);
+                        self
                     }
                 }
             }
-            GetCopy => {
-                let fn_name = Ident::new("get", Span::call_site());
+            Map => {
                 quote! {
                     #(#doc)*
-                    #[inline(always)]
-                    #visibility fn #fn_name(&self) -> #ty {
-                        self.0
+                    #inline
+                    #visibility fn #fn_name(mut self, f: impl ::core::ops::FnOnce(#ty) -> #ty) -> Self {
+                        self.#member = f(self.#member);
+                        self
                     }
                 }
             }
-            Set => {
-                let fn_name = Ident::new("set", Span::call_site());
+            Take => {
+                quote! {
+                    #(#doc)*
+                    #inline
+                    #visibility fn #fn_name(&mut self) -> #ty
+                    where
+                        for<'getset> #ty: ::core::default::Default,
+                    {
+                        ::core::mem::take(&mut self.#member)
+                    }
+                }
+            }
+            Replace => {
+                quote! {
+                    #(#doc)*
+                    #inline
+                    #visibility fn #fn_name(&mut self, val: #ty) -> #ty {
+                        ::core::mem::replace(&mut self.#member, val)
+                    }
+                }
+            }
+            Swap => {
+                quote! {
+                    #(#doc)*
+                    #inline
+                    #visibility fn #fn_name(&mut self, other: &mut Self) {
+                        ::core::mem::swap(&mut self.#member, &mut other.#member)
+                    }
+                }
+            }
+            Collection => {
+                // Every method is named after the field, e.g. `push_field` and
+                // `field_len`, or after the operation alone for unary structs.
+                let name = |prefix: &str, suffix: &str| match position {
+                    None => companion_name(base, rename_all, prefix, suffix),
+                    Some(position) => positional_name(
+                        &format!(
+                            "{}{}",
+                            prefix.trim_end_matches('_'),
+                            suffix.trim_start_matches('_')
+                        ),
+                        position,
+                    ),
+                };
+                let sequence = match (
+                    type_args(&ty, "Vec").as_deref(),
+                    type_args(&ty, "VecDeque").as_deref(),
+                ) {
+                    (Some([item]), _) => Some((*item, quote! { push })),
+                    (_, Some([item])) => Some((*item, quote! { push_back })),
+                    _ => None,
+                };
+                // Maps are looked up through any borrowed form of their key, like std.
+                let map = match (
+                    type_args(&ty, "HashMap").as_deref(),
+                    type_args(&ty, "BTreeMap").as_deref(),
+                ) {
+                    (Some([key, value, hasher @ ..]), _) => Some((
+                        *key,
+                        *value,
+                        quote! {
+                            #key: ::core::cmp::Eq + ::core::hash::Hash,
+                            #(#hasher: ::core::hash::BuildHasher,)*
+                        },
+                        quote! { __Q: ::core::cmp::Eq + ::core::hash::Hash + ?Sized },
+                    )),
+                    (_, Some([key, value])) => Some((
+                        *key,
+                        *value,
+                        quote! { #key: ::core::cmp::Ord, },
+                        quote! { __Q: ::core::cmp::Ord + ?Sized },
+                    )),
+                    _ => None,
+                };
+
+                if let Some((item, push)) = sequence {
+                    let (push_name, extend_name, len_name, is_empty_name, at_name) = (
+                        name("push_", ""),
+                        name("extend_", ""),
+                        name("", "_len"),
+                        name("", "_is_empty"),
+                        name("", "_at"),
+                    );
+
+                    quote! {
+                        #(#doc)*
+                        #inline
+                        #visibility fn #push_name(&mut self, item: #item) -> &mut Self {
+                            self.#member.#push(item);
+                            self
+                        }
+                        #(#doc)*
+                        #inline
+                        #visibility fn #extend_name(
+                            &mut self,
+                            iter: impl ::core::iter::IntoIterator<Item = #item>,
+                        ) -> &mut Self {
+                            ::core::iter::Extend::extend(&mut self.#member, iter);
+                            self
+                        }
+                        #(#doc)*
+                        #inline
+                        #visibility fn #len_name(&self) -> usize {
+                            self.#member.len()
+                        }
+                        #(#doc)*
+                        #inline
+                        #visibility fn #is_empty_name(&self) -> bool {
+                            self.#member.is_empty()
+                        }
+                        #(#doc)*
+                        #inline
+                        #visibility fn #at_name(&self, index: usize) -> ::core::option::Option<&#item> {
+                            self.#member.get(index)
+                        }
+                    }
+                } else if let Some((key, value, key_bounds, query_bounds)) = map {
+                    // A lookup named `get` alone would clash with the unary getter.
+                    let get_name = match position {
+                        None => name("", "_get"),
+                        Some(position) => positional_name("lookup", position),
+                    };
+                    let (insert_name, remove_name, contains_name) = (
+                        name("insert_", ""),
+                        name("remove_", ""),
+                        name("contains_", ""),
+                    );
+
+                    quote! {
+                        #(#doc)*
+                        #inline
+                        #visibility fn #insert_name(
+                            &mut self,
+                            key: #key,
+                            value: #value,
+                        ) -> ::core::option::Option<#value>
+                        where
+                            #key_bounds
+                        {
+                            self.#member.insert(key, value)
+                        }
+                        #(#doc)*
+                        #inline
+                        #visibility fn #remove_name<__Q>(
+                            &mut self,
+                            key: &__Q,
+                        ) -> ::core::option::Option<#value>
+                        where
+                            #key_bounds
+                            #key: ::core::borrow::Borrow<__Q>,
+                            #query_bounds,
+                        {
+                            self.#member.remove(key)
+                        }
+                        #(#doc)*
+                        #inline
+                        #visibility fn #get_name<__Q>(&self, key: &__Q) -> ::core::option::Option<&#value>
+                        where
+                            #key_bounds
+                            #key: ::core::borrow::Borrow<__Q>,
+                            #query_bounds,
+                        {
+                            self.#member.get(key)
+                        }
+                        #(#doc)*
+                        #inline
+                        #visibility fn #contains_name<__Q>(&self, key: &__Q) -> bool
+                        where
+                            #key_bounds
+                            #key: ::core::borrow::Borrow<__Q>,
+                            #query_bounds,
+                        {
+                            self.#member.contains_key(key)
+                        }
+                    }
+                } else {
+                    abort!(
+                        ty.span(),
+                        "`collection` is only supported on `Vec<T>`, `VecDeque<T>`, \
+                         `HashMap<K, V>` and `BTreeMap<K, V>` fields"
+                    )
+                }
+            }
+            Cell => {
+                let inner = match type_args(&ty, "Cell").as_deref() {
+                    Some([inner]) => *inner,
+                    _ => abort!(ty.span(), "`cell` is only supported on `Cell<T>` fields"),
+                };
+                // `field` is accompanied by `set_field`, while the unary `value`
+                // is accompanied by `set_value`.
+                let set_name = match position {
+                    None => companion_name(base, rename_all, "set_", ""),
+                    Some(position) => positional_name("set_value", position),
+                };
+
+                quote! {
+                    #(#doc)*
+                    #inline
+                    #visibility fn #fn_name(&self) -> #inner
+                    where
+                        for<'getset> #inner: ::core::marker::Copy,
+                    {
+                        self.#member.get()
+                    }
+                    #(#doc)*
+                    #inline
+                    #visibility fn #set_name(&self, val: #inner) -> &Self {
+                        self.#member.set(val);
+                        self
+                    }
+                }
+            }
+            RefCell => {
+                let inner = match type_args(&ty, "RefCell").as_deref() {
+                    Some([inner]) => *inner,
+                    _ => abort!(
+                        ty.span(),
+                        "`ref_cell` is only supported on `RefCell<T>` fields"
+                    ),
+                };
+                // Unary structs name their borrows like `RefCell` itself does.
+                let name = |prefix: &str, suffix: &str, unary: &str| match position {
+                    None => companion_name(base, rename_all, prefix, suffix),
+                    Some(position) => positional_name(unary, position),
+                };
+                let mut_name = name("", "_mut", "borrow_mut");
+                // Borrows either panic, return a `Result` through `try_` methods, or both.
+                let policy = option_value(attr.as_ref(), "policy");
+                let (panicking, fallible) = match policy.as_ref().map(LitStr::value).as_deref() {
+                    None | Some("panic") => (true, false),
+                    Some("try") => (false, true),
+                    Some("both") => (true, true),
+                    Some(_) => abort!(
+                        policy.span(),
+                        "`policy` must be one of \"panic\", \"try\" or \"both\""
+                    ),
+                };
+                let panicking = panicking.then(|| {
+                    quote! {
+                        #(#doc)*
+                        #inline
+                        #visibility fn #fn_name(&self) -> ::core::cell::Ref<'_, #inner> {
+                            self.#member.borrow()
+                        }
+                        #(#doc)*
+                        #inline
+                        #visibility fn #mut_name(&self) -> ::core::cell::RefMut<'_, #inner> {
+                            self.#member.borrow_mut()
+                        }
+                    }
+                });
+                let fallible = fallible.then(|| {
+                    let (try_name, try_mut_name) = (
+                        name("try_", "", "try_borrow"),
+                        name("try_", "_mut", "try_borrow_mut"),
+                    );
+                    quote! {
+                        #(#doc)*
+                        #inline
+                        #visibility fn #try_name(
+                            &self,
+                        ) -> ::core::result::Result<
+                            ::core::cell::Ref<'_, #inner>,
+                            ::core::cell::BorrowError,
+                        > {
+                            self.#member.try_borrow()
+                        }
+                        #(#doc)*
+                        #inline
+                        #visibility fn #try_mut_name(
+                            &self,
+                        ) -> ::core::result::Result<
+                            ::core::cell::RefMut<'_, #inner>,
+                            ::core::cell::BorrowMutError,
+                        > {
+                            self.#member.try_borrow_mut()
+                        }
+                    }
+                });
+
+                quote! {
+                    #panicking
+                    #fallible
+                }
+            }
+            Lock => {
+                // Poisoned locks either panic, are recovered from, or are handed to the caller.
+                let poison = option_value(attr.as_ref(), "poison");
+                let guarded = |guard: TokenStream2, acquire: TokenStream2| match poison
+                    .as_ref()
+                    .map(LitStr::value)
+                    .as_deref()
+                {
+                    None | Some("panic") => (guard, quote! { #acquire.unwrap() }),
+                    Some("recover") => (
+                        guard,
+                        quote! { #acquire.unwrap_or_else(::std::sync::PoisonError::into_inner) },
+                    ),
+                    Some("result") => (quote! { ::std::sync::LockResult<#guard> }, acquire),
+                    Some(_) => abort!(
+                        poison.span(),
+                        "`poison` must be one of \"panic\", \"recover\" or \"result\""
+                    ),
+                };
+
+                if let Some([inner]) = type_args(&ty, "Mutex").as_deref() {
+                    let (ret, body) = guarded(
+                        quote! { ::std::sync::MutexGuard<'_, #inner> },
+                        quote! { self.#member.lock() },
+                    );
+                    quote! {
+                        #(#doc)*
+                        #inline
+                        #visibility fn #fn_name(&self) -> #ret {
+                            #body
+                        }
+                    }
+                } else if let Some([inner]) = type_args(&ty, "RwLock").as_deref() {
+                    // `RwLock` fields get `read_field` and `write_field` instead of
+                    // `lock_field`, or `read` and `write` for unary structs.
+                    let (read_name, write_name) = match position {
+                        None => (
+                            companion_name(base, rename_all, "read_", ""),
+                            companion_name(base, rename_all, "write_", ""),
+                        ),
+                        Some(position) => (
+                            positional_name("read", position),
+                            positional_name("write", position),
+                        ),
+                    };
+                    let (read_ret, read_body) = guarded(
+                        quote! { ::std::sync::RwLockReadGuard<'_, #inner> },
+                        quote! { self.#member.read() },
+                    );
+                    let (write_ret, write_body) = guarded(
+                        quote! { ::std::sync::RwLockWriteGuard<'_, #inner> },
+                        quote! { self.#member.write() },
+                    );
+                    quote! {
+                        #(#doc)*
+                        #inline
+                        #visibility fn #read_name(&self) -> #read_ret {
+                            #read_body
+                        }
+                        #(#doc)*
+                        #inline
+                        #visibility fn #write_name(&self) -> #write_ret {
+                            #write_body
+                        }
+                    }
+                } else {
+                    abort!(
+                        ty.span(),
+                        "`lock` is only supported on `Mutex<T>` and `RwLock<T>` fields"
+                    )
+                }
+            }
+            Atomic => {
+                // `AtomicU64` holds a `u64`, `AtomicBool` a `bool` and `AtomicPtr<T>` a `*mut T`.
+                let value = match &ty {
+                    Type::Path(type_path) => type_path.path.segments.last(),
+                    _ => None,
+                }
+                .and_then(|segment| {
+                    let name = segment.ident.to_string();
+                    name.strip_prefix("Atomic").map(str::to_lowercase)
+                });
+                let (value_ty, arithmetic) = match value.as_deref() {
+                    Some("ptr") => match type_args(&ty, "AtomicPtr").as_deref() {
+                        Some([pointee]) => (quote! { *mut #pointee }, false),
+                        _ => abort!(ty.span(), "Expected the pointee type of `AtomicPtr<T>`"),
+                    },
+                    Some("bool") => (quote! { bool }, false),
+                    Some(
+                        value @ ("u8" | "u16" | "u32" | "u64" | "usize" | "i8" | "i16" | "i32"
+                        | "i64" | "isize"),
+                    ) => {
+                        let value = Ident::new(value, Span::call_site());
+                        (quote! { #value }, true)
+                    }
+                    _ => abort!(
+                        ty.span(),
+                        "`atomic` is only supported on the `Atomic*` types of `core::sync::atomic`"
+                    ),
+                };
+
+                // Loads and stores fall back to the strongest ordering they accept, e.g.
+                // `AcqRel` loads with `Acquire` and stores with `Release`.
+                let ordering = option_value(attr.as_ref(), "ordering");
+                let ordering_name = ordering
+                    .as_ref()
+                    .map_or("SeqCst".to_string(), LitStr::value);
+                if !ORDERINGS.contains(&ordering_name.as_str()) {
+                    abort!(
+                        ordering.span(),
+                        "`ordering` must be one of {}",
+                        ORDERINGS.join(", ")
+                    )
+                }
+                let ordering = |name: &str| {
+                    let name = Ident::new(name, Span::call_site());
+                    quote! { ::core::sync::atomic::Ordering::#name }
+                };
+                let load = ordering(match ordering_name.as_str() {
+                    "Release" => "Relaxed",
+                    "AcqRel" => "Acquire",
+                    name => name,
+                });
+                let store = ordering(match ordering_name.as_str() {
+                    "Acquire" => "Relaxed",
+                    "AcqRel" => "Release",
+                    name => name,
+                });
+                let read_write = ordering(&ordering_name);
+
+                let name = |prefix: &str, unary: &str| match position {
+                    None => companion_name(base, rename_all, prefix, ""),
+                    Some(position) => positional_name(unary, position),
+                };
+                let set_name = name("set_", "set_value");
+                let fetch_add = has_option_word(attr.as_ref(), "fetch_add").then(|| {
+                    if !arithmetic {
+                        abort!(
+                            ty.span(),
+                            "`fetch_add` is only supported on integer atomics"
+                        )
+                    }
+                    let fetch_add_name = name("fetch_add_", "fetch_add_value");
+                    quote! {
+                        #(#doc)*
+                        #inline
+                        #visibility fn #fetch_add_name(&self, val: #value_ty) -> #value_ty {
+                            self.#member.fetch_add(val, #read_write)
+                        }
+                    }
+                });
+                let swap = has_option_word(attr.as_ref(), "swap").then(|| {
+                    let swap_name = name("swap_", "swap_value");
+                    quote! {
+                        #(#doc)*
+                        #inline
+                        #visibility fn #swap_name(&self, val: #value_ty) -> #value_ty {
+                            self.#member.swap(val, #read_write)
+                        }
+                    }
+                });
+
                 quote! {
                     #(#doc)*
-                    #[inline(always)]
-                    #visibility fn #fn_name(&mut self, val: #ty) -> &mut Self {
-                        self.0 = val;
+                    #inline
+                    #visibility fn #fn_name(&self) -> #value_ty {
+                        self.#member.load(#load)
+                    }
+                    #(#doc)*
+                    #inline
+                    #visibility fn #set_name(&self, val: #value_ty) -> &Self {
+                        self.#member.store(val, #store);
                         self
                     }
+                    #fetch_add
+                    #swap
+                }
+            }
+            Shared => {
+                let inner = match (
+                    type_args(&ty, "Arc").as_deref(),
+                    type_args(&ty, "Rc").as_deref(),
+                ) {
+                    (Some([inner, ..]), _) | (_, Some([inner, ..])) => *inner,
+                    _ => abort!(
+                        ty.span(),
+                        "`shared` is only supported on `Arc<T>` and `Rc<T>` fields"
+                    ),
+                };
+                let name = |suffix: &str, unary: &str| match position {
+                    None => companion_name(base, rename_all, "", suffix),
+                    Some(position) => positional_name(unary, position),
+                };
+                let shared_name = name("_shared", "shared");
+                // Copy-on-write access requires `T: Clone`, so it is only generated on request.
+                let make_mut = has_option_word(attr.as_ref(), "make_mut").then(|| {
+                    let make_mut_name = name("_make_mut", "make_mut");
+                    quote! {
+                        #(#doc)*
+                        #inline
+                        #visibility fn #make_mut_name(&mut self) -> &mut #inner {
+                            <#ty>::make_mut(&mut self.#member)
+                        }
+                    }
+                });
+
+                quote! {
+                    #(#doc)*
+                    #inline
+                    #visibility fn #fn_name(&self) -> &#inner {
+                        &self.#member
+                    }
+                    #(#doc)*
+                    #inline
+                    #visibility fn #shared_name(&self) -> #ty {
+                        ::core::clone::Clone::clone(&self.#member)
+                    }
+                    #make_mut
+                }
+            }
+            Pin | Unpin => {
+                // A field's own `pin` or `unpin` overrides the one of the struct, but never both.
+                let other = if params.mode == Pin { Unpin } else { Pin };
+                if own_attr(field, other).is_some_and(|meta| !meta.path().is_ident("skip")) {
+                    if own_attr(field, params.mode).is_some() {
+                        abort!(field.span(), "a field cannot be both `pin` and `unpin`")
+                    }
+                    return quote! {};
+                }
+
+                if params.mode == Pin {
+                    // SAFETY: `pin_guards` makes the struct `Unpin` only when its pinned fields
+                    // are, and rejects `Drop` implementations which could move them out.
+                    quote! {
+                        #(#doc)*
+                        #inline
+                        #visibility fn #fn_name(
+                            self: ::core::pin::Pin<&mut Self>,
+                        ) -> ::core::pin::Pin<&mut #ty> {
+                            unsafe { self.map_unchecked_mut(|this| &mut this.#member) }
+                        }
+                    }
+                } else {
+                    // SAFETY: the field is not structurally pinned, so it is never handed out
+                    // as `Pin<&mut _>` and may be moved freely.
+                    quote! {
+                        #(#doc)*
+                        #inline
+                        #visibility fn #fn_name(self: ::core::pin::Pin<&mut Self>) -> &mut #ty {
+                            unsafe { &mut self.get_unchecked_mut().#member }
+                        }
+                    }
                 }
             }
             GetMut => {
-                let fn_name = Ident::new("get_mut", Span::call_site());
                 quote! {
                     #(#doc)*
-                    #[inline(always)]
+                    #inline
                     #visibility fn #fn_name(&mut self) -> &mut #ty {
-                        &mut self.0
+                        &mut self.#member
                     }
                 }
             }
-            SetWith => {
-                let fn_name = Ident::new("set_with", Span::call_site());
+            GetIterMut => {
                 quote! {
                     #(#doc)*
-                    #[inline(always)]
-                    #visibility fn #fn_name(mut self, val: #ty) -> Self {
-                        self.0 = val;
-                        self
+                    #inline
+                    #visibility fn #fn_name<'getset>(
+                        &'getset mut self,
+                    ) -> impl ::core::iter::Iterator<
+                        Item = <&'getset mut #ty as ::core::iter::IntoIterator>::Item,
+                    > + 'getset
+                    where
+                        &'getset mut #ty: ::core::iter::IntoIterator,
+                    {
+                        ::core::iter::IntoIterator::into_iter(&mut self.#member)
+                    }
+                }
+            }
+            GetOptionMut => {
+                if let Some([inner]) = type_args(&ty, "Option").as_deref() {
+                    // `field_mut` becomes `field_get_or_insert_with`, while the
+                    // unary `get_mut` becomes `get_or_insert_with`.
+                    let insert_name = match position {
+                        None => companion_name(base, rename_all, "", "_get_or_insert_with"),
+                        Some(position) => positional_name("get_or_insert_with", position),
+                    };
+
+                    quote! {
+                        #(#doc)*
+                        #inline
+                        #visibility fn #fn_name(&mut self) -> ::core::option::Option<&mut #inner> {
+                            self.#member.as_mut()
+                        }
+                        #(#doc)*
+                        #inline
+                        #visibility fn #insert_name(
+                            &mut self,
+                            f: impl ::core::ops::FnOnce() -> #inner,
+                        ) -> &mut #inner {
+                            self.#member.get_or_insert_with(f)
+                        }
+                    }
+                } else if let Some([inner, error]) = type_args(&ty, "Result").as_deref() {
+                    quote! {
+                        #(#doc)*
+                        #inline
+                        #visibility fn #fn_name(
+                            &mut self,
+                        ) -> ::core::result::Result<&mut #inner, &mut #error> {
+                            self.#member.as_mut()
+                        }
                     }
+                } else {
+                    abort!(
+                        ty.span(),
+                        "`get_option_mut` is only supported on `Option<T>` and `Result<T, E>` fields"
+                    )
+                }
+            }
+            SetWith => {
+                let (val_ty, val) = setter_value(attr.as_ref(), &ty);
+                let setter = if let Some((validate, error)) = setter_validation(attr.as_ref()) {
+                    let stored = stored_value(attr.as_ref(), quote! { val });
+                    quote! {
+                        #(#doc)*
+                        #inline
+                        #visibility fn #fn_name(
+                            mut self,
+                            val: #val_ty,
+                        ) -> ::core::result::Result<Self, #error> {
+                            let val = #val;
+                            #validate(&val)?;
+                            self.#member = #stored;
+                            ::core::result::Result::Ok(self)
+                        }
+                    }
+                } else {
+                    let stored = stored_value(attr.as_ref(), val);
+                    quote! {
+                        #(#doc)*
+                        #inline
+                        #visibility fn #fn_name(mut self, val: #val_ty) -> Self {
+                            self.#member = #stored;
+                            self
+                        }
+                    }
+                };
+                let clear = has_option_word(attr.as_ref(), "strip_option").then(|| {
+                    let clear_name = match position {
+                        None => companion_name(base, rename_all, "without_", ""),
+                        Some(position) => positional_name("without", position),
+                    };
+                    quote! {
+                        #(#doc)*
+                        #inline
+                        #visibility fn #clear_name(mut self) -> Self {
+                            self.#member = ::core::option::Option::None;
+                            self
+                        }
+                    }
+                });
+
+                quote! {
+                    #setter
+                    #clear
                 }
             }
         },
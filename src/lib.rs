@@ -200,6 +200,622 @@ let mut foo = Foo::default();
 let val = foo.get_field();
 ```
 
+Getters for owned containers can return the borrowed form of the field instead, by
+going through its `Deref` implementation with `get_deref`. A `String` then yields `&str`,
+a `Vec<T>` yields `&[T]` and a `Box<T>` yields `&T`. A field asking for `get_deref` or `get_option`
+is left out of a struct-level `get`, since both would generate the same method. A field may
+only ask for both when one of them is given a `name`. The getter only exists when the field's
+type implements `Deref`, so generic fields do not need to be bounded by it.
+
+```rust
+use std::path::{Path, PathBuf};
+
+use getset::Getters;
+
+#[derive(Getters, Default)]
+pub struct Foo {
+    #[getset(get_deref = "pub")]
+    name: String,
+    #[getset(get_deref = "pub")]
+    items: Vec<u8>,
+    #[getset(get_deref = "pub")]
+    path: PathBuf,
+}
+
+let foo = Foo::default();
+let name: &str = foo.name();
+let items: &[u8] = foo.items();
+let path: &Path = foo.path();
+```
+
+`Option<T>` and `Result<T, E>` fields can be read through `as_ref` with `get_option`, which
+returns `Option<&T>` and `Result<&T, &E>` respectively. The `or_default` and `or` options
+additionally generate `field_or_default(&self) -> T` and `field_or(&self, default: &T) -> &T`. The
+former only exists when `T` is `Clone` and `Default`, so it may be asked for on the whole struct.
+
+```rust
+use getset::Getters;
+
+#[derive(Getters)]
+pub struct Foo {
+    #[getset(get_option = "pub or_default or")]
+    name: Option<String>,
+    #[getset(get_option = "pub")]
+    port: Result<u16, String>,
+}
+
+let foo = Foo { name: None, port: Ok(8080) };
+let name: Option<&String> = foo.name();
+let port: Result<&u16, &String> = foo.port();
+let name: String = foo.name_or_default();
+let fallback = "fallback".to_string();
+let name: &String = foo.name_or(&fallback);
+```
+
+Mutable access to the contents of `Option<T>` and `Result<T, E>` fields is provided by
+`get_option_mut`, which returns `Option<&mut T>` and `Result<&mut T, &mut E>` through `as_mut`
+so that the field itself cannot be replaced. For `Option<T>` fields, a
+`field_get_or_insert_with(&mut self, f)` companion is generated as well. A field asking for
+`get_option_mut` is left out of a struct-level `get_mut`.
+
+```rust
+use getset::MutGetters;
+
+#[derive(MutGetters, Default)]
+pub struct Foo {
+    #[getset(get_option_mut = "pub")]
+    name: Option<String>,
+}
+
+let mut foo = Foo::default();
+foo.name_get_or_insert_with(|| "name".to_string()).push('!');
+if let Some(name) = foo.name_mut() {
+    name.make_ascii_uppercase();
+}
+```
+
+Getters can return another type than the one stored in the field by using the list form of
+`get`. The field is passed through `AsRef` with `as_ref = "Target"`, through `Borrow` with
+`borrow = "Target"`, through `Into` with `into = "Target"` (cloning the field first), or
+through a function taking `&T` with `returns = "Target", with = "path::to::fn"`.
+
+```rust
+use std::time::Duration;
+
+use getset::Getters;
+
+#[derive(Getters, Default)]
+pub struct Foo {
+    #[getset(get(vis = "pub", as_ref = "[u8]"))]
+    bytes: Vec<u8>,
+    #[getset(get(vis = "pub", returns = "u64", with = "millis"))]
+    timeout: Duration,
+}
+
+fn millis(duration: &Duration) -> u64 {
+    duration.as_millis() as u64
+}
+
+let foo = Foo::default();
+let bytes: &[u8] = foo.bytes();
+let timeout: u64 = foo.timeout();
+```
+
+Setters can accept any value convertible into the field's type with `into`, which makes
+them take `val: impl Into<T>`.
+
+```rust
+use getset::{Setters, WithSetters};
+
+#[derive(Setters, WithSetters, Default)]
+pub struct Foo {
+    #[getset(set = "pub into", set_with = "pub into")]
+    name: String,
+}
+
+let mut foo = Foo::default();
+foo.set_name("name");
+let foo = foo.with_name('c');
+```
+
+Fallible setters converting their argument with `TryInto` are generated by `set_try`, as
+`try_set_field(&mut self, val) -> Result<&mut Self, _>` and
+`try_with_field(self, val) -> Result<Self, _>`. The field is left untouched if the
+conversion fails.
+
+```rust
+use getset::Setters;
+
+#[derive(Setters, Default)]
+pub struct Foo {
+    #[getset(set_try = "pub")]
+    port: u16,
+}
+
+let mut foo = Foo::default();
+assert!(foo.try_set_port(8080u64).is_ok());
+assert!(foo.try_set_port(u64::MAX).is_err());
+let foo = foo.try_with_port(80u64)?;
+# Ok::<(), std::num::TryFromIntError>(())
+```
+
+Setters can check the value before storing it with `validate = "path::to::fn"`, where the
+validator is a `fn(&T) -> Result<(), E>` and `error = "E"` names its error type. The setters
+then return `Result<&mut Self, E>` and `Result<Self, E>`, leaving the field untouched when the
+validation fails.
+
+```rust
+use getset::{Setters, WithSetters};
+
+#[derive(Setters, WithSetters, Default)]
+pub struct Foo {
+    #[getset(
+        set(vis = "pub", validate = "check_port", error = "String"),
+        set_with(vis = "pub", validate = "check_port", error = "String")
+    )]
+    port: u16,
+}
+
+fn check_port(port: &u16) -> Result<(), String> {
+    if *port >= 1024 {
+        Ok(())
+    } else {
+        Err(format!("{port} is a privileged port"))
+    }
+}
+
+let mut foo = Foo::default();
+assert!(foo.set_port(80).is_err());
+let foo = foo.with_port(8080)?;
+# Ok::<(), String>(())
+```
+
+Setters of `Option<T>` fields can take the inner value with `strip_option`, storing it as
+`Some(val)`. They are then accompanied by `clear_field(&mut self) -> &mut Self` and
+`without_field(self) -> Self` respectively, which reset the field to `None`.
+
+```rust
+use getset::{Setters, WithSetters};
+
+#[derive(Setters, WithSetters, Default)]
+pub struct Foo {
+    #[getset(set = "pub strip_option", set_with = "pub strip_option")]
+    limit: Option<usize>,
+}
+
+let mut foo = Foo::default().with_limit(10);
+foo.clear_limit().set_limit(20);
+let foo = foo.without_limit();
+```
+
+Values can be moved out of fields with `take`, `replace` and `swap`, which generate
+`take_field(&mut self) -> T` (for `T: Default`), `replace_field(&mut self, val: T) -> T`
+returning the old value and `swap_field(&mut self, other: &mut Self)`. `take_field` only
+exists when `T` is `Default`, so `take` may be asked for on the whole struct.
+
+```rust
+use getset::Setters;
+
+#[derive(Setters, Default)]
+pub struct Foo {
+    #[getset(take = "pub", replace = "pub", swap = "pub")]
+    items: Vec<u8>,
+}
+
+let mut foo = Foo::default();
+let mut other = Foo::default();
+let old: Vec<u8> = foo.replace_items(vec![1, 2]);
+foo.swap_items(&mut other);
+let items: Vec<u8> = other.take_items();
+```
+
+Fields can be edited in place through a closure with `update`, generating
+`update_field(&mut self, f: impl FnOnce(&mut T)) -> &mut Self` as part of `MutGetters`, or
+with `map`, generating `map_field(self, f: impl FnOnce(T) -> T) -> Self` as part of
+`WithSetters`. Both can be chained like the `set_` and `with_` setters.
+
+```rust
+use getset::{MutGetters, WithSetters};
+
+#[derive(MutGetters, WithSetters, Default)]
+pub struct Foo {
+    #[getset(update = "pub", map = "pub")]
+    retries: usize,
+}
+
+let mut foo = Foo::default().map_retries(|retries| retries + 3);
+foo.update_retries(|retries| *retries -= 1);
+```
+
+Sequence fields can be kept private while still being edited through `CollectionAccessors`.
+For `Vec<T>` and `VecDeque<T>` fields, `collection` generates `push_field`, `extend_field`,
+`field_len`, `field_is_empty` and the bounds-checked `field_at`. For `HashMap<K, V>` and
+`BTreeMap<K, V>` fields, it generates `insert_field`, `remove_field`, `field_get` and
+`contains_field`, which accept any borrowed form of the key like their std counterparts.
+For unary structs, the lookup is named `lookup` rather than `get`.
+
+```rust
+use std::collections::HashMap;
+
+use getset::CollectionAccessors;
+
+#[derive(CollectionAccessors, Default)]
+pub struct Foo {
+    #[getset(collection = "pub")]
+    items: Vec<u8>,
+    #[getset(collection = "pub")]
+    registry: HashMap<String, u8>,
+}
+
+let mut foo = Foo::default();
+foo.push_items(1).extend_items([2, 3]);
+assert_eq!(3, foo.items_len());
+assert!(!foo.items_is_empty());
+assert_eq!(Some(&2), foo.items_at(1));
+foo.insert_registry("one".to_string(), 1);
+assert!(foo.contains_registry("one"));
+assert_eq!(Some(&1), foo.registry_get("one"));
+assert_eq!(Some(1), foo.remove_registry("one"));
+```
+
+The concrete collection type of a field can be hidden behind an iterator with `get_iter`,
+generating `field_iter(&self) -> impl Iterator<Item = &T>`, and `get_iter_mut` as part of
+`MutGetters`, generating `field_iter_mut(&mut self) -> impl Iterator<Item = &mut T>`. Any field
+whose references implement `IntoIterator` is supported.
+
+```rust
+use getset::{Getters, MutGetters};
+
+#[derive(Getters, MutGetters, Default)]
+pub struct Foo {
+    #[getset(get_iter = "pub", get_iter_mut = "pub")]
+    items: Vec<u8>,
+}
+
+let mut foo = Foo::default();
+foo.items_iter_mut().for_each(|item| *item += 1);
+let total: u8 = foo.items_iter().sum();
+```
+
+`Cell<T>` fields are accessed through `&self` with `CellAccessors`. The `cell` mode generates
+`field(&self) -> T` through `Cell::get` and `set_field(&self, val: T) -> &Self` through
+`Cell::set`. The getter only exists when `T` is `Copy`, while the setter is available for
+any `T`. The field of a unary struct is accessed through `value` and `set_value`.
+
+```rust
+use std::cell::Cell;
+
+use getset::CellAccessors;
+
+#[derive(CellAccessors, Default)]
+pub struct Foo {
+    #[getset(cell = "pub")]
+    hits: Cell<usize>,
+}
+
+let foo = Foo::default();
+foo.set_hits(foo.hits() + 1);
+assert_eq!(1, foo.hits());
+```
+
+`RefCell<T>` fields are borrowed through `ref_cell`, also part of `CellAccessors`, which
+generates `field(&self) -> Ref<'_, T>` and `field_mut(&self) -> RefMut<'_, T>`. These panic
+when the field is already borrowed incompatibly, like `RefCell::borrow`. With
+`policy = "try"`, `try_field` and `try_field_mut` are generated instead, returning a
+`BorrowError` or `BorrowMutError` rather than panicking, and `policy = "both"` generates all four.
+
+```rust
+use std::cell::RefCell;
+
+use getset::CellAccessors;
+
+#[derive(CellAccessors, Default)]
+pub struct Foo {
+    #[getset(ref_cell(vis = "pub", policy = "both"))]
+    cache: RefCell<Vec<u8>>,
+    #[getset(ref_cell(vis = "pub", policy = "try"))]
+    log: RefCell<String>,
+}
+
+let foo = Foo::default();
+foo.cache_mut().push(1);
+assert_eq!(vec![1], *foo.cache());
+let guard = foo.cache_mut();
+assert!(foo.try_cache().is_err());
+drop(guard);
+foo.try_log_mut().unwrap().push('!');
+```
+
+Shared state behind a `Mutex<T>` or `RwLock<T>` is accessed through `LockAccessors`. The
+`lock` mode generates `lock_field(&self) -> MutexGuard<'_, T>` for `Mutex<T>` fields, and
+`read_field` and `write_field` returning the read and write guards for `RwLock<T>` fields.
+Poisoned locks panic by default, which `poison = "recover"` replaces with recovering the guard
+through `PoisonError::into_inner`, and `poison = "result"` with returning the `LockResult`.
+
+```rust
+use std::sync::{Mutex, RwLock};
+
+use getset::LockAccessors;
+
+#[derive(LockAccessors, Default)]
+pub struct Foo {
+    #[getset(lock = "pub")]
+    queue: Mutex<Vec<u8>>,
+    #[getset(lock(vis = "pub", poison = "recover"))]
+    config: RwLock<String>,
+}
+
+let foo = Foo::default();
+foo.lock_queue().push(1);
+foo.write_config().push_str("verbose");
+assert_eq!("verbose", *foo.read_config());
+```
+
+Atomic fields are accessed through `AtomicAccessors`. The `atomic` mode generates
+`field(&self) -> T` through `load` and `set_field(&self, val: T) -> &Self` through `store`,
+where `T` is the atomic's value type, e.g. `u64` for `AtomicU64` or `*mut T` for
+`AtomicPtr<T>`. The `fetch_add` and `swap` options additionally generate `fetch_add_field`
+and `swap_field`. The memory ordering is `SeqCst` unless given with `ordering`. Loads and
+stores use the nearest ordering they accept, e.g. `AcqRel` loads with `Acquire` and stores
+with `Release`. The field of a unary struct is accessed through `value`, `set_value`,
+`fetch_add_value` and `swap_value`.
+
+```rust
+use std::sync::atomic::{AtomicBool, AtomicU64};
+
+use getset::AtomicAccessors;
+
+#[derive(AtomicAccessors, Default)]
+pub struct Foo {
+    #[getset(atomic(vis = "pub", ordering = "AcqRel", fetch_add))]
+    requests: AtomicU64,
+    #[getset(atomic = "pub swap")]
+    ready: AtomicBool,
+}
+
+let foo = Foo::default();
+foo.set_requests(1).fetch_add_requests(2);
+assert_eq!(3, foo.requests());
+assert!(!foo.swap_ready(true));
+assert!(foo.ready());
+```
+
+`Arc<T>` and `Rc<T>` fields are accessed through `SharedAccessors`. The `shared` mode
+generates `field(&self) -> &T`, hiding the pointer, and `field_shared(&self)` returning a
+clone of the pointer itself. The `make_mut` option additionally generates
+`field_make_mut(&mut self) -> &mut T` for copy-on-write editing through `Arc::make_mut` or
+`Rc::make_mut`, which requires `T: Clone`. The field of a unary struct is accessed through
+`value`, `shared` and `make_mut`.
+
+```rust
+use std::sync::Arc;
+
+use getset::SharedAccessors;
+
+#[derive(SharedAccessors, Default)]
+pub struct Foo {
+    #[getset(shared = "pub make_mut")]
+    config: Arc<String>,
+}
+
+let mut foo = Foo::default();
+let snapshot: Arc<String> = foo.config_shared();
+foo.config_make_mut().push_str("edited");
+assert_eq!("edited", foo.config());
+assert_eq!("", *snapshot);
+```
+
+Hand-written futures can project a pinned struct onto its fields with `PinProjections`.
+Structurally pinned fields are marked with `pin`, generating
+`field_pin_mut(self: Pin<&mut Self>) -> Pin<&mut T>`. Fields marked with `unpin` are never
+pinned, and get `field_mut(self: Pin<&mut Self>) -> &mut T` instead. To keep the projections
+sound, the derive implements `Unpin` for the struct only when its pinned fields are `Unpin`.
+The struct may therefore neither implement `Unpin` nor `Drop` itself, nor be
+`#[repr(packed)]`.
+
+```rust
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use getset::PinProjections;
+
+#[derive(PinProjections)]
+pub struct Counted<F> {
+    #[getset(pin)]
+    inner: F,
+    #[getset(unpin)]
+    polls: usize,
+}
+
+impl<F: Future> Future for Counted<F> {
+    type Output = (F::Output, usize);
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        *self.as_mut().polls_mut() += 1;
+        match self.as_mut().inner_pin_mut().poll(cx) {
+            Poll::Ready(output) => Poll::Ready((output, *self.polls_mut())),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+let mut counted = Box::pin(Counted { inner: std::future::ready(1), polls: 0 });
+let mut cx = Context::from_waker(std::task::Waker::noop());
+assert_eq!(Poll::Ready((1, 1)), counted.as_mut().poll(&mut cx));
+```
+
+A `Drop` implementation could move a pinned field out, so it is rejected:
+
+```compile_fail,E0119
+use getset::PinProjections;
+
+#[derive(PinProjections)]
+pub struct Guarded<F> {
+    #[getset(pin)]
+    inner: F,
+}
+
+impl<F> Drop for Guarded<F> {
+    fn drop(&mut self) {}
+}
+```
+
+So is implementing `Unpin` regardless of the pinned fields:
+
+```compile_fail,E0119
+use getset::PinProjections;
+
+#[derive(PinProjections)]
+pub struct Unpinned<F> {
+    #[getset(pin)]
+    inner: F,
+}
+
+impl<F> Unpin for Unpinned<F> {}
+```
+
+A pinned field which is not `Unpin` keeps the struct from being `Unpin`:
+
+```compile_fail,E0277
+use std::marker::PhantomPinned;
+
+use getset::PinProjections;
+
+#[derive(PinProjections)]
+pub struct Pinned {
+    #[getset(pin)]
+    inner: PhantomPinned,
+}
+
+fn assert_unpin<T: Unpin>() {}
+assert_unpin::<Pinned>();
+```
+
+And packed structs are rejected, since their fields cannot be referenced in place:
+
+```compile_fail
+use getset::PinProjections;
+
+#[derive(PinProjections)]
+#[repr(packed)]
+pub struct Packed {
+    #[getset(pin)]
+    inner: u8,
+}
+```
+
+The name of a generated method can be chosen per field with `name`, replacing the one derived
+from the field's name along with any prefix or suffix. Companion methods, like the `clear_`
+setter of `strip_option`, are named after it.
+
+```rust
+use getset::{Getters, Setters};
+
+#[derive(Getters, Setters, Default)]
+pub struct Foo {
+    #[getset(get(vis = "pub", name = "id"), set(vis = "pub", name = "assign_id"))]
+    inner_id_raw: u64,
+}
+
+let mut foo = Foo::default();
+foo.assign_id(3);
+assert_eq!(3, *foo.id());
+```
+
+The prefix and suffix surrounding the field's name can be changed per mode with `prefix` and
+`suffix`, on the struct or on a field. A getter `prefix` takes the place of `with_prefix`,
+and any other mode's replaces its own, like the `set_` of setters. Modes naming several
+methods after the field, such as `collection`, `cell`, `ref_cell`, `lock`, `atomic` and
+`shared`, do not accept them.
+
+```rust
+use getset::{Getters, MutGetters, Setters};
+
+#[derive(Getters, MutGetters, Setters, Default)]
+#[getset(set(vis = "pub", prefix = "put_"), get_mut(vis = "pub", suffix = "_mut_ref"))]
+pub struct Foo {
+    #[getset(get(vis = "pub", prefix = "fetch_"))]
+    count: usize,
+}
+
+let mut foo = Foo::default();
+foo.put_count(1);
+*foo.count_mut_ref() += 1;
+assert_eq!(2, *foo.fetch_count());
+```
+
+Method names can follow another case than snake_case with `rename_all`, given on the struct
+or on a field to override the struct's. The cases are those of serde: `"lowercase"`,
+`"UPPERCASE"`, `"PascalCase"`, `"camelCase"`, `"snake_case"` and `"SCREAMING_SNAKE_CASE"`,
+while `"kebab-case"` and `"SCREAMING-KEBAB-CASE"` give their snake_case equivalents since
+identifiers cannot contain hyphens.
+Methods named with `name` are kept as is.
+
+```rust
+use getset::{Getters, Setters};
+
+#[derive(Getters, Setters, Default)]
+#[getset(rename_all = "camelCase", get = "pub", set = "pub")]
+pub struct Foo {
+    max_retries: usize,
+    #[getset(rename_all = "snake_case")]
+    time_out: u64,
+}
+
+let mut foo = Foo::default();
+foo.setMaxRetries(3).set_time_out(10);
+assert_eq!(3, *foo.maxRetries());
+assert_eq!(10, *foo.time_out());
+```
+
+Every mode also accepts its options as a list, e.g. `get(vis = "pub(crate)", prefix)`, where
+a bare `prefix` is the same as `with_prefix`. The list form can also control inlining:
+generated methods are `#[inline(always)]` by default, `#[inline]` with `inline` and
+`#[inline(never)]` with `inline = "never"`. An option unknown to the mode is reported at the
+option itself, in the list form as well as among the words of a string. Only `with_prefix`
+is accepted in the string of any mode, and ignored by those other than getters.
+
+```rust
+use getset::{Getters, Setters};
+
+#[derive(Getters, Setters, Default)]
+pub struct Foo {
+    #[getset(
+        get(vis = "pub(crate)", prefix, inline = "never"),
+        set(vis = "pub", into, inline)
+    )]
+    name: String,
+}
+
+let mut foo = Foo::default();
+foo.set_name("name");
+assert_eq!("name", foo.get_name());
+```
+
+The visibility in an attribute string can be any Rust visibility, including
+`pub(in path)`, `pub(self)` and `pub(super)`, and may be combined with option words.
+
+```rust
+mod net {
+    pub mod proto {
+        use getset::Getters;
+
+        #[derive(Getters, Default)]
+        pub struct Foo {
+            #[getset(get = "pub(in super) with_prefix")]
+            port: u16,
+        }
+    }
+
+    pub fn port(foo: &proto::Foo) -> u16 {
+        *foo.get_port()
+    }
+}
+
+assert_eq!(0, net::port(&net::proto::Foo::default()));
+```
+
 Skipping setters and getters generation for a field when struct level attribute is used
 is possible with `#[getset(skip)]`.
 
@@ -258,6 +874,26 @@ struct CopyUnaryTuple(#[getset(get_copy)] i32);
 
 let tup = CopyUnaryTuple(42);
 ```
+
+For a tuple struct with several fields, the methods are named as for a unary struct and
+suffixed with the field's position, e.g. `get_0`, `set_1`, `value_0` or `lookup_1`, unless
+the field is given a `name`. Since these names do not come from the fields, `rename_all`,
+`prefix` and `suffix` are rejected on tuple structs, except for the affixes of a field
+given a `name`.
+
+```rust
+use getset::{CopyGetters, Setters};
+
+#[derive(CopyGetters, Setters, Default)]
+#[getset(get_copy, set)]
+struct Point(#[getset(get_copy(name = "x"), set(name = "set_x"))] f32, f32, f32);
+
+let mut point = Point::default();
+point.set_x(1.0).set_1(2.0);
+assert_eq!(point.x(), 1.0);
+assert_eq!(point.get_1(), 2.0);
+assert_eq!(point.get_2(), 0.0);
+```
 */
 
 #[macro_use]
@@ -272,16 +908,24 @@ use crate::generate::{GenMode, GenParams};
 
 mod generate;
 
-#[proc_macro_derive(Getters, attributes(get, with_prefix, getset))]
+#[proc_macro_derive(
+    Getters,
+    attributes(get, get_deref, get_option, get_iter, with_prefix, getset)
+)]
 #[proc_macro_error]
 pub fn getters(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
-    let params = GenParams {
-        mode: GenMode::Get,
-        global_attr: parse_global_attr(&ast.attrs, GenMode::Get),
-    };
 
-    produce(&ast, &params).into()
+    produce_modes(
+        &ast,
+        &[
+            GenMode::Get,
+            GenMode::GetDeref,
+            GenMode::GetOption,
+            GenMode::GetIter,
+        ],
+    )
+    .into()
 }
 
 #[proc_macro_derive(CloneGetters, attributes(get_clone, with_prefix, getset))]
@@ -291,6 +935,7 @@ pub fn clone_getters(input: TokenStream) -> TokenStream {
     let params = GenParams {
         mode: GenMode::GetClone,
         global_attr: parse_global_attr(&ast.attrs, GenMode::GetClone),
+        rename_all: parse_rename_all(&ast.attrs),
     };
 
     produce(&ast, &params).into()
@@ -303,47 +948,141 @@ pub fn copy_getters(input: TokenStream) -> TokenStream {
     let params = GenParams {
         mode: GenMode::GetCopy,
         global_attr: parse_global_attr(&ast.attrs, GenMode::GetCopy),
+        rename_all: parse_rename_all(&ast.attrs),
     };
 
     produce(&ast, &params).into()
 }
 
-#[proc_macro_derive(MutGetters, attributes(get_mut, getset))]
+#[proc_macro_derive(
+    MutGetters,
+    attributes(get_mut, get_option_mut, get_iter_mut, update, getset)
+)]
 #[proc_macro_error]
 pub fn mut_getters(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
+
+    produce_modes(
+        &ast,
+        &[
+            GenMode::GetMut,
+            GenMode::GetOptionMut,
+            GenMode::GetIterMut,
+            GenMode::Update,
+        ],
+    )
+    .into()
+}
+
+#[proc_macro_derive(Setters, attributes(set, set_try, take, replace, swap, getset))]
+#[proc_macro_error]
+pub fn setters(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    produce_modes(
+        &ast,
+        &[
+            GenMode::Set,
+            GenMode::SetTry,
+            GenMode::Take,
+            GenMode::Replace,
+            GenMode::Swap,
+        ],
+    )
+    .into()
+}
+
+#[proc_macro_derive(WithSetters, attributes(set_with, map, getset))]
+#[proc_macro_error]
+pub fn with_setters(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    produce_modes(&ast, &[GenMode::SetWith, GenMode::Map]).into()
+}
+
+#[proc_macro_derive(CollectionAccessors, attributes(collection, getset))]
+#[proc_macro_error]
+pub fn collection_accessors(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
     let params = GenParams {
-        mode: GenMode::GetMut,
-        global_attr: parse_global_attr(&ast.attrs, GenMode::GetMut),
+        mode: GenMode::Collection,
+        global_attr: parse_global_attr(&ast.attrs, GenMode::Collection),
+        rename_all: parse_rename_all(&ast.attrs),
     };
 
     produce(&ast, &params).into()
 }
 
-#[proc_macro_derive(Setters, attributes(set, getset))]
+#[proc_macro_derive(CellAccessors, attributes(cell, ref_cell, getset))]
 #[proc_macro_error]
-pub fn setters(input: TokenStream) -> TokenStream {
+pub fn cell_accessors(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    produce_modes(&ast, &[GenMode::Cell, GenMode::RefCell]).into()
+}
+
+#[proc_macro_derive(LockAccessors, attributes(lock, getset))]
+#[proc_macro_error]
+pub fn lock_accessors(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
     let params = GenParams {
-        mode: GenMode::Set,
-        global_attr: parse_global_attr(&ast.attrs, GenMode::Set),
+        mode: GenMode::Lock,
+        global_attr: parse_global_attr(&ast.attrs, GenMode::Lock),
+        rename_all: parse_rename_all(&ast.attrs),
     };
 
     produce(&ast, &params).into()
 }
 
-#[proc_macro_derive(WithSetters, attributes(set_with, getset))]
+#[proc_macro_derive(AtomicAccessors, attributes(atomic, getset))]
 #[proc_macro_error]
-pub fn with_setters(input: TokenStream) -> TokenStream {
+pub fn atomic_accessors(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
     let params = GenParams {
-        mode: GenMode::SetWith,
-        global_attr: parse_global_attr(&ast.attrs, GenMode::SetWith),
+        mode: GenMode::Atomic,
+        global_attr: parse_global_attr(&ast.attrs, GenMode::Atomic),
+        rename_all: parse_rename_all(&ast.attrs),
     };
 
     produce(&ast, &params).into()
 }
 
+#[proc_macro_derive(SharedAccessors, attributes(shared, getset))]
+#[proc_macro_error]
+pub fn shared_accessors(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    let params = GenParams {
+        mode: GenMode::Shared,
+        global_attr: parse_global_attr(&ast.attrs, GenMode::Shared),
+        rename_all: parse_rename_all(&ast.attrs),
+    };
+
+    produce(&ast, &params).into()
+}
+
+#[proc_macro_derive(PinProjections, attributes(pin, unpin, getset))]
+#[proc_macro_error]
+pub fn pin_projections(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    let params = GenParams {
+        mode: GenMode::Pin,
+        global_attr: parse_global_attr(&ast.attrs, GenMode::Pin),
+        rename_all: parse_rename_all(&ast.attrs),
+    };
+    if params.global_attr.is_some() && parse_global_attr(&ast.attrs, GenMode::Unpin).is_some() {
+        abort_call_site!("a struct cannot be both `pin` and `unpin`");
+    }
+
+    let projections = produce_modes(&ast, &[GenMode::Pin, GenMode::Unpin]);
+    let guards = generate::pin_guards(&ast, &params);
+
+    quote! {
+        #projections
+        #guards
+    }
+    .into()
+}
+
 fn parse_global_attr(attrs: &[syn::Attribute], mode: GenMode) -> Option<Meta> {
     attrs.iter().filter_map(|v| parse_attr(v, mode)).next_back()
 }
@@ -361,13 +1100,11 @@ fn parse_attr(attr: &syn::Attribute, mode: GenMode) -> Option<syn::Meta> {
         let (last, skip, mut collected) = meta_list
             .into_iter()
             .inspect(|meta| {
-                if !(meta.path().is_ident("get")
-                    || meta.path().is_ident("get_clone")
-                    || meta.path().is_ident("get_copy")
-                    || meta.path().is_ident("get_mut")
-                    || meta.path().is_ident("set")
-                    || meta.path().is_ident("set_with")
-                    || meta.path().is_ident("skip"))
+                if !(GenMode::ALL
+                    .iter()
+                    .any(|mode| meta.path().is_ident(mode.name()))
+                    || meta.path().is_ident("skip")
+                    || meta.path().is_ident("rename_all"))
                 {
                     abort!(meta.path().span(), "unknown setter or getter")
                 }
@@ -379,6 +1116,8 @@ fn parse_attr(attr: &syn::Attribute, mode: GenMode) -> Option<syn::Meta> {
                         (Some(meta), skip, collected)
                     } else if meta.path().is_ident("skip") {
                         (last, Some(meta), collected)
+                    } else if meta.path().is_ident("rename_all") {
+                        (last, skip, collected)
                     } else {
                         collected.push(meta);
                         (last, skip, collected)
@@ -409,31 +1148,88 @@ fn parse_attr(attr: &syn::Attribute, mode: GenMode) -> Option<syn::Meta> {
     }
 }
 
+/// Parses the case given with `#[getset(rename_all = "...")]`, if any.
+fn parse_rename_all(attrs: &[syn::Attribute]) -> Option<syn::LitStr> {
+    use syn::{Expr, ExprLit, Lit, Token, punctuated::Punctuated};
+
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("getset"))
+        .flat_map(|attr| {
+            match attr.parse_args_with(Punctuated::<syn::Meta, Token![,]>::parse_terminated) {
+                Ok(list) => list,
+                Err(e) => abort!(attr.span(), "Failed to parse getset attribute: {}", e),
+            }
+        })
+        .filter_map(|meta| match meta {
+            Meta::NameValue(name_value) if name_value.path.is_ident("rename_all") => {
+                match name_value.value {
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(lit), ..
+                    }) => Some(lit),
+                    value => abort!(value.span(), "Expected a string value for `rename_all`"),
+                }
+            }
+            _ => None,
+        })
+        .next_back()
+}
+
+/// Runs `produce` once per mode for derives which handle several modes.
+fn produce_modes(ast: &DeriveInput, modes: &[GenMode]) -> TokenStream2 {
+    modes
+        .iter()
+        .map(|&mode| {
+            let params = GenParams {
+                mode,
+                global_attr: parse_global_attr(&ast.attrs, mode),
+                rename_all: parse_rename_all(&ast.attrs),
+            };
+
+            produce(ast, &params)
+        })
+        .collect()
+}
+
 fn produce(ast: &DeriveInput, params: &GenParams) -> TokenStream2 {
     let name = &ast.ident;
+    // Renamed methods are not snake_case anymore.
+    let allow_renamed = generate::has_rename_all(ast).then(|| quote! { #[allow(non_snake_case)] });
     let generics = &ast.generics;
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     // Is it a struct?
     if let syn::Data::Struct(DataStruct { ref fields, .. }) = ast.data {
         // Handle unary struct
-        if matches!(fields, syn::Fields::Unnamed(_)) {
-            if fields.len() != 1 {
-                abort_call_site!("Only support unary struct!");
-            }
+        if matches!(fields, syn::Fields::Unnamed(_)) && fields.len() == 1 {
             // This unwrap is safe because we know there is exactly one field
             let field = fields.iter().next().unwrap();
             let generated = generate::implement_for_unnamed(field, params);
 
             quote! {
+                #allow_renamed
                 impl #impl_generics #name #ty_generics #where_clause {
                     #generated
                 }
             }
+        } else if matches!(fields, syn::Fields::Unnamed(_)) {
+            // Fields of other tuple structs are told apart by their position
+            let generated = fields
+                .iter()
+                .enumerate()
+                .map(|(index, f)| generate::implement_for_position(f, index, params));
+
+            quote! {
+                #allow_renamed
+                impl #impl_generics #name #ty_generics #where_clause {
+                    #(#generated)*
+                }
+            }
         } else {
             let generated = fields.iter().map(|f| generate::implement(f, params));
 
             quote! {
+                #allow_renamed
                 impl #impl_generics #name #ty_generics #where_clause {
                     #(#generated)*
                 }
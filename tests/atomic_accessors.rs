@@ -0,0 +1,93 @@
+#[macro_use]
+extern crate getset;
+
+use std::ptr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicI32, AtomicPtr, AtomicU64};
+use std::thread;
+
+#[derive(AtomicAccessors, Default)]
+#[atomic]
+struct Counters {
+    /// A doc comment.
+    /// Multiple lines, even.
+    #[atomic = "pub fetch_add swap"]
+    pub count: AtomicU64,
+
+    #[getset(atomic(vis = "pub", ordering = "Acquire"))]
+    acquired: AtomicI32,
+
+    #[getset(atomic(vis = "pub", ordering = "AcqRel", swap))]
+    flag: AtomicBool,
+
+    #[getset(atomic(vis = "pub", ordering = "Release", swap))]
+    pointer: AtomicPtr<u8>,
+}
+
+#[test]
+fn test_plain() {
+    let val = Counters::default();
+    val.set_count(1).set_acquired(-1);
+    assert_eq!(1, val.count());
+    assert_eq!(-1, val.acquired());
+}
+
+#[test]
+fn test_fetch_add_and_swap() {
+    let val = Counters::default();
+    assert_eq!(0, val.fetch_add_count(2));
+    assert_eq!(2, val.swap_count(5));
+    assert_eq!(5, val.count());
+    assert!(!val.swap_flag(true));
+    assert!(val.flag());
+}
+
+#[test]
+fn test_pointer() {
+    let mut byte = 1u8;
+    let val = Counters::default();
+    assert!(val.pointer().is_null());
+    val.set_pointer(&mut byte);
+    assert_eq!(&mut byte as *mut u8, val.swap_pointer(ptr::null_mut()));
+    assert!(val.pointer().is_null());
+}
+
+#[test]
+fn test_threads() {
+    let val = Arc::new(Counters::default());
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let val = Arc::clone(&val);
+            thread::spawn(move || {
+                val.fetch_add_count(1);
+            })
+        })
+        .collect();
+    handles
+        .into_iter()
+        .for_each(|handle| handle.join().unwrap());
+    assert_eq!(4, val.count());
+}
+
+#[test]
+fn test_unary_tuple() {
+    #[derive(AtomicAccessors)]
+    struct Counter(#[getset(atomic = "fetch_add swap")] AtomicU64);
+
+    #[derive(AtomicAccessors)]
+    struct Slot(#[getset(atomic)] AtomicPtr<u8>);
+
+    #[derive(AtomicAccessors)]
+    struct Ready(#[getset(atomic(ordering = "Relaxed"))] AtomicBool);
+
+    let val = Counter(AtomicU64::new(1));
+    val.set_value(2).fetch_add_value(1);
+    assert_eq!(3, val.swap_value(4));
+    assert_eq!(4, val.value());
+
+    let val = Slot(AtomicPtr::default());
+    assert!(val.value().is_null());
+
+    let val = Ready(AtomicBool::new(false));
+    assert!(val.set_value(true).value());
+}
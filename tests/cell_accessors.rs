@@ -0,0 +1,74 @@
+#[macro_use]
+extern crate getset;
+
+use std::cell::Cell;
+
+#[derive(CellAccessors, Default)]
+#[cell]
+struct Counter {
+    /// A doc comment.
+    /// Multiple lines, even.
+    count: Cell<usize>,
+
+    #[getset(cell = "pub")]
+    flag: Cell<bool>,
+}
+
+#[test]
+fn test_plain() {
+    let val = Counter::default();
+    val.set_count(1).set_flag(true);
+    assert_eq!(1, val.count());
+    assert!(val.flag());
+}
+
+#[test]
+fn test_shared_reference() {
+    let val = Counter::default();
+    let first = &val;
+    let second = &val;
+    first.set_count(first.count() + 1);
+    second.set_count(second.count() + 1);
+    assert_eq!(2, val.count());
+}
+
+#[test]
+fn test_generic() {
+    // The getter only exists when `T` is `Copy`.
+    #[derive(CellAccessors, Default)]
+    #[cell]
+    struct Slot<T> {
+        value: Cell<T>,
+    }
+
+    let val = Slot::<u8>::default();
+    val.set_value(3);
+    assert_eq!(3, val.value());
+
+    let val = Slot::<String>::default();
+    val.set_value("name".to_string());
+    assert_eq!("name", val.value.take());
+}
+
+#[test]
+fn test_non_copy() {
+    #[derive(CellAccessors, Default)]
+    struct Label {
+        #[getset(cell)]
+        name: Cell<String>,
+    }
+
+    let val = Label::default();
+    val.set_name("name".to_string());
+    assert_eq!("name", val.name.take());
+}
+
+#[test]
+fn test_unary_tuple() {
+    #[derive(CellAccessors)]
+    struct Counter(#[getset(cell)] Cell<u32>);
+
+    let val = Counter(Cell::new(1));
+    val.set_value(val.value() + 1);
+    assert_eq!(2, val.value());
+}
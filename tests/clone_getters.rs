@@ -7,7 +7,7 @@ use std::sync::Arc;
 
 // For testing `pub(super)`
 mod submodule {
-    // For testing `pub(super::other)`
+    // For testing `pub(in super::other)`
     pub mod other {
         use std::rc::Rc;
         use std::sync::Arc;
@@ -22,17 +22,18 @@ mod submodule {
             /// A doc comment.
             #[get_clone = "pub"]
             public_accessible: Box<usize>,
-            // /// A doc comment.
-            // #[get_clone = "pub(crate)"]
-            // crate_accessible: Box<usize>,
 
-            // /// A doc comment.
-            // #[get_clone = "pub(super)"]
-            // super_accessible: Box<usize>,
+            /// A doc comment.
+            #[get_clone = "pub(crate)"]
+            crate_accessible: Box<usize>,
+
+            /// A doc comment.
+            #[get_clone = "pub(super)"]
+            super_accessible: Box<usize>,
 
-            // /// A doc comment.
-            // #[get_clone = "pub(super::other)"]
-            // scope_accessible: Box<usize>,
+            /// A doc comment.
+            #[get_clone = "pub(in super::other)"]
+            scope_accessible: Box<usize>,
 
             // Prefixed getter.
             #[get_clone = "with_prefix"]
@@ -48,6 +49,9 @@ mod submodule {
                 Plain {
                     private_accessible: Box::new(17),
                     public_accessible: Box::new(18),
+                    crate_accessible: Box::new(21),
+                    super_accessible: Box::new(22),
+                    scope_accessible: Box::new(23),
                     private_prefixed: Box::new(19),
                     public_prefixed: Box::new(20),
                 }
@@ -64,17 +68,18 @@ mod submodule {
             /// A doc comment.
             #[get_clone = "pub"]
             public_accessible: Arc<usize>,
-            // /// A doc comment.
-            // #[get_clone = "pub(crate)"]
-            // crate_accessible: Arc<usize>,
 
-            // /// A doc comment.
-            // #[get_clone = "pub(super)"]
-            // super_accessible: Arc<usize>,
+            /// A doc comment.
+            #[get_clone = "pub(crate)"]
+            crate_accessible: Arc<usize>,
+
+            /// A doc comment.
+            #[get_clone = "pub(super)"]
+            super_accessible: Arc<usize>,
 
-            // /// A doc comment.
-            // #[get_clone = "pub(super::other)"]
-            // scope_accessible: Arc<usize>,
+            /// A doc comment.
+            #[get_clone = "pub(in super::other)"]
+            scope_accessible: Arc<usize>,
 
             // Prefixed getter.
             #[get_clone = "with_prefix"]
@@ -90,6 +95,9 @@ mod submodule {
                 ReferenceCounted {
                     private_accessible: Arc::new(17),
                     public_accessible: Arc::new(18),
+                    crate_accessible: Arc::new(21),
+                    super_accessible: Arc::new(22),
+                    scope_accessible: Arc::new(23),
                     private_prefixed: Arc::new(19),
                     public_prefixed: Arc::new(20),
                 }
@@ -106,17 +114,18 @@ mod submodule {
             /// A doc comment.
             #[get_clone = "pub"]
             public_accessible: T,
-            // /// A doc comment.
-            // #[get_clone = "pub(crate)"]
-            // crate_accessible: T,
 
-            // /// A doc comment.
-            // #[get_clone = "pub(super)"]
-            // super_accessible: T,
+            /// A doc comment.
+            #[get_clone = "pub(crate)"]
+            crate_accessible: T,
+
+            /// A doc comment.
+            #[get_clone = "pub(super)"]
+            super_accessible: T,
 
-            // /// A doc comment.
-            // #[get_clone = "pub(super::other)"]
-            // scope_accessible: T,
+            /// A doc comment.
+            #[get_clone = "pub(in super::other)"]
+            scope_accessible: T,
         }
 
         #[derive(CloneGetters, Getters, Default)]
@@ -132,53 +141,78 @@ mod submodule {
             /// A doc comment.
             #[get_clone = "pub"]
             public_accessible: T,
-            // /// A doc comment.
-            // #[get_clone = "pub(crate)"]
-            // crate_accessible: T,
 
-            // /// A doc comment.
-            // #[get_clone = "pub(super)"]
-            // super_accessible: T,
+            /// A doc comment.
+            #[get_clone = "pub(crate)"]
+            crate_accessible: T,
+
+            /// A doc comment.
+            #[get_clone = "pub(super)"]
+            super_accessible: T,
 
-            // /// A doc comment.
-            // #[get_clone = "pub(super::other)"]
-            // scope_accessible: T,
+            /// A doc comment.
+            #[get_clone = "pub(in super::other)"]
+            scope_accessible: T,
         }
 
         #[test]
         fn test_plain() {
             let val = Plain::default();
             val.private_accessible();
+            val.crate_accessible();
+            val.super_accessible();
+            val.scope_accessible();
         }
 
         #[test]
         fn test_reference_counted() {
             let val = ReferenceCounted::default();
             val.private_accessible();
+            val.crate_accessible();
+            val.super_accessible();
+            val.scope_accessible();
         }
 
         #[test]
         fn test_generic() {
             let val = Generic::<Box<usize>>::default();
             val.private_accessible();
+            val.crate_accessible();
+            val.super_accessible();
+            val.scope_accessible();
 
             let val = Generic::<Rc<usize>>::default();
             val.private_accessible();
+            val.crate_accessible();
+            val.super_accessible();
+            val.scope_accessible();
 
             let val = Generic::<Arc<usize>>::default();
             val.private_accessible();
+            val.crate_accessible();
+            val.super_accessible();
+            val.scope_accessible();
         }
 
         #[test]
         fn test_where() {
             let val = Where::<Box<usize>>::default();
             val.private_accessible();
+            val.crate_accessible();
+            val.super_accessible();
+            val.scope_accessible();
 
             let val = Where::<Rc<usize>>::default();
             val.private_accessible();
+            val.crate_accessible();
+            val.super_accessible();
+            val.scope_accessible();
 
             let val = Where::<Arc<usize>>::default();
             val.private_accessible();
+            val.crate_accessible();
+            val.super_accessible();
+            val.scope_accessible();
         }
 
         #[test]
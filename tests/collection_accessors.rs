@@ -0,0 +1,135 @@
+#[macro_use]
+extern crate getset;
+
+use std::collections::VecDeque;
+
+use crate::submodule::other::{Generic, Plain, Where};
+
+// For testing `pub(super)`
+mod submodule {
+    // For testing `pub(in super::other)`
+    pub mod other {
+        use std::collections::VecDeque;
+
+        #[derive(CollectionAccessors, Default)]
+        #[collection]
+        pub struct Plain {
+            /// A doc comment.
+            /// Multiple lines, even.
+            private_accessible: Vec<usize>,
+
+            /// A doc comment.
+            #[collection = "pub"]
+            public_accessible: Vec<usize>,
+
+            #[getset(collection = "pub")]
+            queue: VecDeque<String>,
+        }
+
+        #[derive(CollectionAccessors, Default)]
+        #[collection]
+        pub struct Generic<T> {
+            /// A doc comment.
+            /// Multiple lines, even.
+            private_accessible: Vec<T>,
+
+            /// A doc comment.
+            #[collection = "pub"]
+            public_accessible: Vec<T>,
+        }
+
+        #[derive(CollectionAccessors, Default)]
+        #[collection]
+        pub struct Where<T>
+        where
+            T: Clone,
+        {
+            /// A doc comment.
+            /// Multiple lines, even.
+            private_accessible: Vec<T>,
+
+            /// A doc comment.
+            #[collection = "pub"]
+            public_accessible: Vec<T>,
+        }
+
+        #[test]
+        fn test_plain() {
+            let mut val = Plain::default();
+            val.push_private_accessible(1);
+            assert_eq!(1, val.private_accessible_len());
+        }
+
+        #[test]
+        fn test_generic() {
+            let mut val = Generic::<usize>::default();
+            val.push_private_accessible(1);
+            assert_eq!(1, val.private_accessible_len());
+        }
+
+        #[test]
+        fn test_where() {
+            let mut val = Where::<usize>::default();
+            val.push_private_accessible(1);
+            assert_eq!(1, val.private_accessible_len());
+        }
+
+        #[test]
+        fn test_queue_order() {
+            let mut val = Plain::default();
+            val.extend_queue(["first".to_string(), "second".to_string()]);
+            assert_eq!(Some(&"second".to_string()), val.queue.back());
+        }
+    }
+}
+
+#[test]
+fn test_plain() {
+    let mut val = Plain::default();
+    assert!(val.public_accessible_is_empty());
+    val.push_public_accessible(1)
+        .extend_public_accessible([2, 3]);
+    assert!(!val.public_accessible_is_empty());
+    assert_eq!(3, val.public_accessible_len());
+    assert_eq!(Some(&1), val.public_accessible_at(0));
+    assert_eq!(Some(&3), val.public_accessible_at(2));
+    assert_eq!(None, val.public_accessible_at(3));
+}
+
+#[test]
+fn test_vec_deque() {
+    let mut val = Plain::default();
+    val.push_queue("first".to_string())
+        .extend_queue(VecDeque::from(["second".to_string()]));
+    assert_eq!(2, val.queue_len());
+    assert_eq!(Some(&"first".to_string()), val.queue_at(0));
+    assert_eq!(Some(&"second".to_string()), val.queue_at(1));
+}
+
+#[test]
+fn test_generic() {
+    let mut val = Generic::<usize>::default();
+    val.extend_public_accessible(0..4);
+    assert_eq!(4, val.public_accessible_len());
+    assert_eq!(Some(&3), val.public_accessible_at(3));
+}
+
+#[test]
+fn test_where() {
+    let mut val = Where::<usize>::default();
+    val.extend_public_accessible(0..4);
+    assert_eq!(4, val.public_accessible_len());
+    assert_eq!(Some(&3), val.public_accessible_at(3));
+}
+
+#[test]
+fn test_unary_tuple() {
+    #[derive(CollectionAccessors, Default)]
+    struct Stack(#[getset(collection)] Vec<u8>);
+
+    let mut val = Stack::default();
+    assert!(val.is_empty());
+    val.push(1).extend([2, 3]);
+    assert_eq!(3, val.len());
+    assert_eq!(Some(&2), val.at(1));
+}
@@ -0,0 +1,129 @@
+#[macro_use]
+extern crate getset;
+
+use std::time::Duration;
+
+use crate::submodule::other::{Generic, Plain};
+
+#[derive(Clone, Copy, Default)]
+pub struct Meters(u64);
+
+impl From<Meters> for u64 {
+    fn from(meters: Meters) -> u64 {
+        meters.0
+    }
+}
+
+fn millis(duration: &Duration) -> u64 {
+    duration.as_millis() as u64
+}
+
+// For testing `pub(super)`
+mod submodule {
+    // For testing `pub(in super::other)`
+    pub mod other {
+        use std::time::Duration;
+
+        use crate::Meters;
+
+        #[derive(Getters, Default)]
+        pub struct Plain {
+            /// A doc comment.
+            /// Multiple lines, even.
+            #[getset(get(as_ref = "[u8]"))]
+            private_accessible: Vec<u8>,
+
+            /// A doc comment.
+            #[getset(get(vis = "pub", as_ref = "[u8]"))]
+            public_accessible: Vec<u8>,
+
+            #[getset(get(vis = "pub", borrow = "str"))]
+            borrowed: String,
+
+            #[getset(get(vis = "pub", into = "u64"))]
+            distance: Meters,
+
+            #[getset(get(vis = "pub", returns = "u64", with = "crate::millis"))]
+            timeout: Duration,
+
+            // Prefixed getter.
+            #[getset(get(vis = "pub", with_prefix, as_ref = "str"))]
+            public_prefixed: String,
+        }
+
+        #[derive(Getters, Default)]
+        pub struct Generic<T: Default> {
+            /// A doc comment.
+            /// Multiple lines, even.
+            #[getset(get(as_ref = "[T]"))]
+            private_accessible: Vec<T>,
+
+            /// A doc comment.
+            #[getset(get(vis = "pub", borrow = "T"))]
+            public_accessible: Box<T>,
+        }
+
+        #[test]
+        fn test_plain() {
+            let val = Plain::default();
+            let _: &[u8] = val.private_accessible();
+        }
+
+        #[test]
+        fn test_generic() {
+            let val = Generic::<usize>::default();
+            let _: &[usize] = val.private_accessible();
+        }
+    }
+}
+
+#[test]
+fn test_as_ref() {
+    let val = Plain::default();
+    let public: &[u8] = val.public_accessible();
+    assert!(public.is_empty());
+}
+
+#[test]
+fn test_borrow() {
+    let val = Plain::default();
+    let borrowed: &str = val.borrowed();
+    assert_eq!("", borrowed);
+}
+
+#[test]
+fn test_into() {
+    let val = Plain::default();
+    let distance: u64 = val.distance();
+    assert_eq!(0, distance);
+}
+
+#[test]
+fn test_with() {
+    let val = Plain::default();
+    let timeout: u64 = val.timeout();
+    assert_eq!(0, timeout);
+}
+
+#[test]
+fn test_generic() {
+    let val = Generic::<usize>::default();
+    let public: &usize = val.public_accessible();
+    assert_eq!(usize::default(), *public);
+}
+
+#[test]
+fn test_prefixed_plain() {
+    let val = Plain::default();
+    let prefixed: &str = val.get_public_prefixed();
+    assert_eq!("", prefixed);
+}
+
+#[test]
+fn test_unary_tuple() {
+    #[derive(Getters)]
+    struct Distance(#[getset(get(into = "u64"))] Meters);
+
+    let val = Distance(Meters(42));
+    assert_eq!(42, val.get());
+}
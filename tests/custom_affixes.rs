@@ -0,0 +1,121 @@
+#[macro_use]
+extern crate getset;
+
+use crate::submodule::other::{Generic, Plain, Where};
+
+// For testing `pub(super)`
+mod submodule {
+    // For testing `pub(in super::other)`
+    pub mod other {
+        #[derive(Getters, MutGetters, Setters, Default)]
+        #[getset(
+            get(prefix = "fetch_"),
+            get_mut(suffix = "_mut_ref"),
+            set(prefix = "put_")
+        )]
+        pub struct Plain {
+            /// A doc comment.
+            /// Multiple lines, even.
+            private_accessible: usize,
+
+            /// A doc comment.
+            #[getset(
+                get(vis = "pub", prefix = "read_"),
+                get_mut(vis = "pub", prefix = "edit_", suffix = ""),
+                set(vis = "pub", prefix = "", suffix = "_put")
+            )]
+            public_accessible: usize,
+
+            #[getset(set(vis = "pub", prefix = "put_", strip_option))]
+            pub label: Option<String>,
+
+            #[getset(get_option(vis = "pub", prefix = "get_", or_default))]
+            pub limit: Option<usize>,
+
+            #[getset(get(vis = "pub", prefix = ""))]
+            r#type: usize,
+        }
+
+        #[derive(Getters, Setters, Default)]
+        #[getset(get(prefix = "fetch_"), set(prefix = "put_"))]
+        pub struct Generic<T> {
+            /// A doc comment.
+            /// Multiple lines, even.
+            private_accessible: T,
+
+            /// A doc comment.
+            #[getset(get(vis = "pub", prefix = "fetch_"), set(vis = "pub", prefix = "put_"))]
+            public_accessible: T,
+        }
+
+        #[derive(Getters, Setters, Default)]
+        #[getset(get(prefix = "fetch_"), set(prefix = "put_"))]
+        pub struct Where<T>
+        where
+            T: Clone,
+        {
+            /// A doc comment.
+            /// Multiple lines, even.
+            private_accessible: T,
+
+            /// A doc comment.
+            #[getset(get(vis = "pub", prefix = "fetch_"), set(vis = "pub", prefix = "put_"))]
+            public_accessible: T,
+        }
+
+        #[test]
+        fn test_plain() {
+            let mut val = Plain::default();
+            val.put_private_accessible(1);
+            *val.private_accessible_mut_ref() += 1;
+            assert_eq!(2, *val.fetch_private_accessible());
+        }
+
+        #[test]
+        fn test_generic() {
+            let mut val = Generic::<usize>::default();
+            val.put_private_accessible(1);
+            assert_eq!(1, *val.fetch_private_accessible());
+        }
+
+        #[test]
+        fn test_where() {
+            let mut val = Where::<usize>::default();
+            val.put_private_accessible(1);
+            assert_eq!(1, *val.fetch_private_accessible());
+        }
+    }
+}
+
+#[test]
+fn test_plain() {
+    let mut val = Plain::default();
+    val.public_accessible_put(1);
+    *val.edit_public_accessible() += 1;
+    assert_eq!(2, *val.read_public_accessible());
+    assert_eq!(0, *val.r#type());
+}
+
+#[test]
+fn test_companions() {
+    let mut val = Plain::default();
+    val.put_label("label".to_string());
+    assert_eq!(Some("label"), val.label.as_deref());
+    val.clear_label();
+    assert_eq!(None, val.label);
+    assert_eq!(0, val.get_limit_or_default());
+}
+
+#[test]
+fn test_generic() {
+    let mut val = Generic::<usize>::default();
+    val.put_public_accessible(1);
+    assert_eq!(1, *val.fetch_public_accessible());
+}
+
+#[test]
+fn test_where() {
+    let mut val = Where::<usize>::default();
+    val.put_public_accessible(1);
+    assert_eq!(1, *val.fetch_public_accessible());
+}
@@ -0,0 +1,133 @@
+#[macro_use]
+extern crate getset;
+
+use crate::submodule::other::{Generic, Plain, Where};
+
+// For testing `pub(super)`
+mod submodule {
+    // For testing `pub(in super::other)`
+    pub mod other {
+        #[derive(CopyGetters, MutGetters, Setters, WithSetters, Default)]
+        #[getset(get_mut, set, set_with)]
+        pub struct Plain {
+            /// A doc comment.
+            /// Multiple lines, even.
+            #[getset(get_copy(name = "private"))]
+            private_accessible: usize,
+
+            /// A doc comment.
+            #[getset(
+                get_copy(vis = "pub", name = "public"),
+                get_mut(vis = "pub", name = "public_ref"),
+                set(vis = "pub", name = "assign_public"),
+                set_with(vis = "pub", name = "and_public")
+            )]
+            public_accessible: usize,
+
+            #[getset(set(vis = "pub", name = "assign_label", strip_option))]
+            pub label: Option<String>,
+
+            #[getset(get_copy(vis = "pub", with_prefix, name = "r#type"))]
+            kind: u8,
+        }
+
+        #[derive(Getters, Setters, Default)]
+        #[getset(get, set)]
+        pub struct Generic<T> {
+            /// A doc comment.
+            /// Multiple lines, even.
+            #[getset(get(name = "private"))]
+            private_accessible: T,
+
+            /// A doc comment.
+            #[getset(get(vis = "pub", name = "public"), set(vis = "pub", name = "assign"))]
+            public_accessible: T,
+        }
+
+        #[derive(Getters, Setters, Default)]
+        #[getset(get, set)]
+        pub struct Where<T>
+        where
+            T: Clone,
+        {
+            /// A doc comment.
+            /// Multiple lines, even.
+            #[getset(get(name = "private"))]
+            private_accessible: T,
+
+            /// A doc comment.
+            #[getset(get(vis = "pub", name = "public"), set(vis = "pub", name = "assign"))]
+            public_accessible: T,
+        }
+
+        #[test]
+        fn test_plain() {
+            let mut val = Plain::default();
+            val.set_private_accessible(1);
+            assert_eq!(1, val.private());
+        }
+
+        #[test]
+        fn test_generic() {
+            let mut val = Generic::<usize>::default();
+            val.set_private_accessible(1);
+            assert_eq!(1, *val.private());
+        }
+
+        #[test]
+        fn test_where() {
+            let mut val = Where::<usize>::default();
+            val.set_private_accessible(1);
+            assert_eq!(1, *val.private());
+        }
+    }
+}
+
+#[test]
+fn test_plain() {
+    let mut val = Plain::default();
+    val.assign_public(1);
+    *val.public_ref() += 1;
+    let public = val.public();
+    let val = val.and_public(public + 1);
+    assert_eq!(3, val.public());
+}
+
+#[test]
+fn test_companion() {
+    let mut val = Plain::default();
+    val.assign_label("label".to_string());
+    assert_eq!(Some("label"), val.label.as_deref());
+    val.clear_assign_label();
+    assert_eq!(None, val.label);
+}
+
+#[test]
+fn test_raw_name() {
+    let val = Plain::default();
+    assert_eq!(0, val.r#type());
+}
+
+#[test]
+fn test_generic() {
+    let mut val = Generic::<usize>::default();
+    val.assign(1);
+    assert_eq!(1, *val.public());
+}
+
+#[test]
+fn test_where() {
+    let mut val = Where::<usize>::default();
+    val.assign(1);
+    assert_eq!(1, *val.public());
+}
+
+#[test]
+fn test_unary_tuple() {
+    #[derive(CopyGetters, Setters)]
+    struct Id(#[getset(get_copy(name = "value"), set(name = "assign"))] u64);
+
+    let mut val = Id(1);
+    val.assign(2);
+    assert_eq!(2, val.value());
+}
@@ -0,0 +1,236 @@
+#[macro_use]
+extern crate getset;
+
+use std::path::Path;
+use std::rc::Rc;
+
+use crate::submodule::other::{Generic, Plain, Where};
+
+// For testing `pub(super)`
+mod submodule {
+    // For testing `pub(in super::other)`
+    pub mod other {
+        use std::path::PathBuf;
+        use std::rc::Rc;
+
+        #[derive(Getters)]
+        #[get_deref]
+        pub struct Plain {
+            /// A doc comment.
+            /// Multiple lines, even.
+            private_accessible: String,
+
+            /// A doc comment.
+            #[get_deref = "pub"]
+            public_accessible: String,
+
+            #[getset(get_deref = "pub")]
+            vec: Vec<usize>,
+
+            #[getset(get_deref = "pub")]
+            boxed: Box<usize>,
+
+            #[getset(get_deref = "pub")]
+            path: PathBuf,
+
+            #[getset(get_deref = "pub")]
+            shared: Rc<str>,
+
+            // Prefixed getter.
+            #[get_deref = "with_prefix"]
+            private_prefixed: String,
+
+            // Prefixed getter.
+            #[get_deref = "pub with_prefix"]
+            public_prefixed: String,
+        }
+
+        impl Default for Plain {
+            fn default() -> Plain {
+                Plain {
+                    private_accessible: "17".to_string(),
+                    public_accessible: "18".to_string(),
+                    vec: vec![1, 2, 3],
+                    boxed: Box::new(4),
+                    path: PathBuf::from("/tmp"),
+                    shared: Rc::from("shared"),
+                    private_prefixed: "19".to_string(),
+                    public_prefixed: "20".to_string(),
+                }
+            }
+        }
+
+        #[derive(Getters, Default)]
+        #[get_deref]
+        pub struct Generic<T: Default> {
+            /// A doc comment.
+            /// Multiple lines, even.
+            private_accessible: Vec<T>,
+
+            /// A doc comment.
+            #[get_deref = "pub"]
+            public_accessible: Box<T>,
+        }
+
+        #[derive(Getters, Default)]
+        #[get_deref]
+        pub struct Where<T>
+        where
+            T: Default,
+        {
+            /// A doc comment.
+            /// Multiple lines, even.
+            private_accessible: Vec<T>,
+
+            /// A doc comment.
+            #[get_deref = "pub"]
+            public_accessible: Box<T>,
+        }
+
+        #[test]
+        fn test_plain() {
+            let val = Plain::default();
+            let _: &str = val.private_accessible();
+        }
+
+        #[test]
+        fn test_generic() {
+            let val = Generic::<usize>::default();
+            let _: &[usize] = val.private_accessible();
+        }
+
+        #[test]
+        fn test_where() {
+            let val = Where::<usize>::default();
+            let _: &[usize] = val.private_accessible();
+        }
+
+        #[test]
+        fn test_prefixed_plain() {
+            let val = Plain::default();
+            assert_eq!("19", val.get_private_prefixed());
+        }
+    }
+}
+
+#[test]
+fn test_plain() {
+    let val = Plain::default();
+    let public: &str = val.public_accessible();
+    assert_eq!("18", public);
+}
+
+#[test]
+fn test_containers() {
+    let val = Plain::default();
+    let vec: &[usize] = val.vec();
+    let boxed: &usize = val.boxed();
+    let path: &Path = val.path();
+    let shared: &str = val.shared();
+    assert_eq!(&[1, 2, 3], vec);
+    assert_eq!(4, *boxed);
+    assert_eq!(Path::new("/tmp"), path);
+    assert_eq!("shared", shared);
+}
+
+#[test]
+fn test_generic() {
+    let val = Generic::<usize>::default();
+    let public: &usize = val.public_accessible();
+    assert_eq!(usize::default(), *public);
+}
+
+#[test]
+fn test_where() {
+    let val = Where::<usize>::default();
+    let public: &usize = val.public_accessible();
+    assert_eq!(usize::default(), *public);
+}
+
+#[test]
+fn test_prefixed_plain() {
+    let val = Plain::default();
+    assert_eq!("20", val.get_public_prefixed());
+}
+
+#[test]
+fn test_alongside_get() {
+    #[derive(Getters)]
+    struct Both {
+        #[getset(get)]
+        owned: String,
+        #[getset(get_deref)]
+        borrowed: String,
+    }
+
+    let val = Both {
+        owned: "owned".to_string(),
+        borrowed: "borrowed".to_string(),
+    };
+    let owned: &String = val.owned();
+    let borrowed: &str = val.borrowed();
+    assert_eq!("owned", owned);
+    assert_eq!("borrowed", borrowed);
+}
+
+#[test]
+fn test_unary_tuple() {
+    #[derive(Getters)]
+    struct Name(#[getset(get_deref)] Rc<String>);
+
+    let name = Name(Rc::new("name".to_string()));
+    let _: &String = name.get();
+    assert_eq!("name", name.get());
+}
+
+#[test]
+fn test_overriding_struct_get() {
+    #[derive(Getters)]
+    #[getset(get = "pub")]
+    struct Both {
+        owned: String,
+        #[getset(get_deref = "pub")]
+        borrowed: String,
+    }
+
+    let val = Both {
+        owned: "owned".to_string(),
+        borrowed: "borrowed".to_string(),
+    };
+    let owned: &String = val.owned();
+    let borrowed: &str = val.borrowed();
+    assert_eq!("owned", owned);
+    assert_eq!("borrowed", borrowed);
+}
+
+#[test]
+fn test_conditional_on_deref() {
+    #[derive(Getters)]
+    struct Wrapper<T> {
+        #[getset(get_deref)]
+        inner: T,
+    }
+
+    // The getter only exists for `Deref` types, without requiring it of every `T`.
+    let _ = Wrapper { inner: 1usize };
+    let val = Wrapper {
+        inner: "inner".to_string(),
+    };
+    assert_eq!("inner", val.inner());
+}
+
+#[test]
+fn test_alongside_named_get() {
+    #[derive(Getters)]
+    struct Both {
+        #[getset(get(name = "raw_name"), get_deref)]
+        name: String,
+    }
+
+    let val = Both {
+        name: "name".to_string(),
+    };
+    let raw: &String = val.raw_name();
+    let name: &str = val.name();
+    assert_eq!(raw, name);
+}
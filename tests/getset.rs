@@ -17,17 +17,18 @@ mod submodule {
             /// A doc comment.
             #[getset(get = "pub", get_mut = "pub")]
             public_accessible: usize,
-            // /// A doc comment.
-            // #[get_mut = "pub(crate)"]
-            // crate_accessible: usize,
 
-            // /// A doc comment.
-            // #[get_mut = "pub(super)"]
-            // super_accessible: usize,
+            /// A doc comment.
+            #[get_mut = "pub(crate)"]
+            crate_accessible: usize,
+
+            /// A doc comment.
+            #[get_mut = "pub(super)"]
+            super_accessible: usize,
 
-            // /// A doc comment.
-            // #[get_mut = "pub(in super::other)"]
-            // scope_accessible: usize,
+            /// A doc comment.
+            #[get_mut = "pub(in super::other)"]
+            scope_accessible: usize,
         }
 
         #[derive(MutGetters, Getters, Default)]
@@ -40,17 +41,18 @@ mod submodule {
             /// A doc comment.
             #[getset(get = "pub", get_mut = "pub")]
             public_accessible: T,
-            // /// A doc comment.
-            // #[get_mut = "pub(crate)"]
-            // crate_accessible: usize,
 
-            // /// A doc comment.
-            // #[get_mut = "pub(super)"]
-            // super_accessible: usize,
+            /// A doc comment.
+            #[get_mut = "pub(crate)"]
+            crate_accessible: usize,
 
-            // /// A doc comment.
-            // #[get_mut = "pub(in super::other)"]
-            // scope_accessible: usize,
+            /// A doc comment.
+            #[get_mut = "pub(super)"]
+            super_accessible: usize,
+
+            /// A doc comment.
+            #[get_mut = "pub(in super::other)"]
+            scope_accessible: usize,
         }
 
         #[derive(MutGetters, Getters, Default)]
@@ -66,35 +68,45 @@ mod submodule {
             /// A doc comment.
             #[getset(get = "pub", get_mut = "pub")]
             public_accessible: T,
-            // /// A doc comment.
-            // #[get_mut = "pub(crate)"]
-            // crate_accessible: usize,
 
-            // /// A doc comment.
-            // #[get_mut = "pub(super)"]
-            // super_accessible: usize,
+            /// A doc comment.
+            #[get_mut = "pub(crate)"]
+            crate_accessible: usize,
 
-            // /// A doc comment.
-            // #[get_mut = "pub(in super::other)"]
-            // scope_accessible: usize,
+            /// A doc comment.
+            #[get_mut = "pub(super)"]
+            super_accessible: usize,
+
+            /// A doc comment.
+            #[get_mut = "pub(in super::other)"]
+            scope_accessible: usize,
         }
 
         #[test]
         fn test_plain() {
             let mut val = Plain::default();
             (*val.private_accessible_mut()) += 1;
+            (*val.crate_accessible_mut()) += 1;
+            (*val.super_accessible_mut()) += 1;
+            (*val.scope_accessible_mut()) += 1;
         }
 
         #[test]
         fn test_generic() {
             let mut val = Generic::<usize>::default();
             (*val.private_accessible_mut()) += 1;
+            (*val.crate_accessible_mut()) += 1;
+            (*val.super_accessible_mut()) += 1;
+            (*val.scope_accessible_mut()) += 1;
         }
 
         #[test]
         fn test_where() {
             let mut val = Where::<usize>::default();
             (*val.private_accessible_mut()) += 1;
+            (*val.crate_accessible_mut()) += 1;
+            (*val.super_accessible_mut()) += 1;
+            (*val.scope_accessible_mut()) += 1;
         }
     }
 }
@@ -119,3 +131,21 @@ fn test_where() {
     let _ = val.public_accessible();
     (*val.public_accessible_mut()) += 1;
 }
+
+#[test]
+fn test_shared_prefix_string() {
+    #[derive(Getters, Setters, WithSetters, Default)]
+    #[getset(
+        get = "pub with_prefix",
+        set = "pub with_prefix",
+        set_with = "pub with_prefix"
+    )]
+    struct Shared {
+        a: usize,
+    }
+
+    let mut val = Shared::default().with_a(1);
+    assert_eq!(1, *val.get_a());
+    val.set_a(2);
+    assert_eq!(2, *val.get_a());
+}
@@ -5,7 +5,7 @@ use crate::submodule::other::{Generic, Plain, Where};
 
 // For testing `pub(super)`
 mod submodule {
-    // For testing `pub(super::other)`
+    // For testing `pub(in super::other)`
     pub mod other {
         #[derive(Getters)]
         #[get]
@@ -17,17 +17,18 @@ mod submodule {
             /// A doc comment.
             #[get = "pub"]
             public_accessible: usize,
-            // /// A doc comment.
-            // #[get = "pub(crate)"]
-            // crate_accessible: usize,
 
-            // /// A doc comment.
-            // #[get = "pub(super)"]
-            // super_accessible: usize,
+            /// A doc comment.
+            #[get = "pub(crate)"]
+            crate_accessible: usize,
+
+            /// A doc comment.
+            #[get = "pub(super)"]
+            super_accessible: usize,
 
-            // /// A doc comment.
-            // #[get = "pub(super::other)"]
-            // scope_accessible: usize,
+            /// A doc comment.
+            #[get = "pub(in super::other)"]
+            scope_accessible: usize,
 
             // Prefixed getter.
             #[get = "with_prefix"]
@@ -43,6 +44,9 @@ mod submodule {
                 Plain {
                     private_accessible: 17,
                     public_accessible: 18,
+                    crate_accessible: 21,
+                    super_accessible: 22,
+                    scope_accessible: 23,
                     private_prefixed: 19,
                     public_prefixed: 20,
                 }
@@ -59,17 +63,18 @@ mod submodule {
             /// A doc comment.
             #[get = "pub(crate)"]
             public_accessible: T,
-            // /// A doc comment.
-            // #[get = "pub(crate)"]
-            // crate_accessible: usize,
 
-            // /// A doc comment.
-            // #[get = "pub(super)"]
-            // super_accessible: usize,
+            /// A doc comment.
+            #[get = "pub(crate)"]
+            crate_accessible: usize,
 
-            // /// A doc comment.
-            // #[get = "pub(super::other)"]
-            // scope_accessible: usize,
+            /// A doc comment.
+            #[get = "pub(super)"]
+            super_accessible: usize,
+
+            /// A doc comment.
+            #[get = "pub(in super::other)"]
+            scope_accessible: usize,
         }
 
         #[derive(Getters, Default)]
@@ -85,35 +90,45 @@ mod submodule {
             /// A doc comment.
             #[get = "pub"]
             public_accessible: T,
-            // /// A doc comment.
-            // #[get = "pub(crate)"]
-            // crate_accessible: usize,
 
-            // /// A doc comment.
-            // #[get = "pub(super)"]
-            // super_accessible: usize,
+            /// A doc comment.
+            #[get = "pub(crate)"]
+            crate_accessible: usize,
 
-            // /// A doc comment.
-            // #[get = "pub(super::other)"]
-            // scope_accessible: usize,
+            /// A doc comment.
+            #[get = "pub(super)"]
+            super_accessible: usize,
+
+            /// A doc comment.
+            #[get = "pub(in super::other)"]
+            scope_accessible: usize,
         }
 
         #[test]
         fn test_plain() {
             let val = Plain::default();
             val.private_accessible();
+            val.crate_accessible();
+            val.super_accessible();
+            val.scope_accessible();
         }
 
         #[test]
         fn test_generic() {
             let val = Generic::<usize>::default();
             val.private_accessible();
+            val.crate_accessible();
+            val.super_accessible();
+            val.scope_accessible();
         }
 
         #[test]
         fn test_where() {
             let val = Where::<usize>::default();
             val.private_accessible();
+            val.crate_accessible();
+            val.super_accessible();
+            val.scope_accessible();
         }
 
         #[test]
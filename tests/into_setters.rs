@@ -0,0 +1,147 @@
+#[macro_use]
+extern crate getset;
+
+use std::path::PathBuf;
+
+use crate::submodule::other::{Generic, Plain, Where};
+
+// For testing `pub(super)`
+mod submodule {
+    // For testing `pub(in super::other)`
+    pub mod other {
+        use std::path::PathBuf;
+
+        #[derive(Setters, WithSetters, Default)]
+        #[getset(set = "into", set_with = "into")]
+        pub struct Plain {
+            /// A doc comment.
+            /// Multiple lines, even.
+            pub private_accessible: String,
+
+            /// A doc comment.
+            #[getset(set = "pub into", set_with = "pub into")]
+            pub public_accessible: String,
+
+            /// This field is used for testing chaining.
+            #[getset(set = "pub into", set_with = "pub into")]
+            pub second_public_accessible: PathBuf,
+
+            #[getset(set(vis = "pub", into), set_with(vis = "pub", into))]
+            pub listed: u64,
+
+            /// Setters without `into` keep taking the field's type.
+            #[getset(set = "pub", set_with = "pub")]
+            pub exact: u64,
+        }
+
+        #[derive(Setters, WithSetters, Default)]
+        #[getset(set = "into", set_with = "into")]
+        pub struct Generic<T: Default> {
+            /// A doc comment.
+            /// Multiple lines, even.
+            pub private_accessible: T,
+
+            /// A doc comment.
+            #[getset(set = "pub into", set_with = "pub into")]
+            pub public_accessible: T,
+        }
+
+        #[derive(Setters, WithSetters, Default)]
+        #[getset(set = "into", set_with = "into")]
+        pub struct Where<T>
+        where
+            T: Default,
+        {
+            /// A doc comment.
+            /// Multiple lines, even.
+            pub private_accessible: T,
+
+            /// A doc comment.
+            #[getset(set = "pub into", set_with = "pub into")]
+            pub public_accessible: T,
+        }
+
+        #[test]
+        fn test_plain() {
+            let mut val = Plain::default();
+            val.set_private_accessible("private");
+            assert_eq!("private", val.private_accessible);
+            let val = val.with_private_accessible('c');
+            assert_eq!("c", val.private_accessible);
+        }
+
+        #[test]
+        fn test_generic() {
+            let mut val = Generic::<u64>::default();
+            val.set_private_accessible(1u8);
+            assert_eq!(1, val.private_accessible);
+        }
+
+        #[test]
+        fn test_where() {
+            let mut val = Where::<u64>::default();
+            val.set_private_accessible(1u32);
+            assert_eq!(1, val.private_accessible);
+        }
+    }
+}
+
+#[test]
+fn test_plain() {
+    let mut val = Plain::default();
+    val.set_public_accessible("public");
+    assert_eq!("public", val.public_accessible);
+    let val = val.with_public_accessible(String::from("with"));
+    assert_eq!("with", val.public_accessible);
+}
+
+#[test]
+fn test_list() {
+    let mut val = Plain::default();
+    val.set_listed(1u8).set_exact(2);
+    assert_eq!((1, 2), (val.listed, val.exact));
+    let val = val.with_listed(3u16).with_exact(4);
+    assert_eq!((3, 4), (val.listed, val.exact));
+}
+
+#[test]
+fn test_generic() {
+    let mut val = Generic::<u64>::default();
+    val.set_public_accessible(1u16);
+    assert_eq!(1, val.public_accessible);
+    let val = val.with_public_accessible(2u32);
+    assert_eq!(2, val.public_accessible);
+}
+
+#[test]
+fn test_where() {
+    let mut val = Where::<u64>::default();
+    val.set_public_accessible(1u16);
+    assert_eq!(1, val.public_accessible);
+    let val = val.with_public_accessible(2u32);
+    assert_eq!(2, val.public_accessible);
+}
+
+#[test]
+fn test_chaining() {
+    let mut val = Plain::default();
+    val.set_public_accessible("public")
+        .set_second_public_accessible("/tmp");
+    assert_eq!(PathBuf::from("/tmp"), val.second_public_accessible);
+    let val = Plain::default()
+        .with_public_accessible("public")
+        .with_second_public_accessible("/tmp");
+    assert_eq!("public", val.public_accessible);
+}
+
+#[test]
+fn test_unary_tuple() {
+    #[derive(Setters, WithSetters, Default)]
+    struct Name(#[getset(set = "into", set_with = "into")] String);
+
+    let mut val = Name::default();
+    val.set("name");
+    assert_eq!("name", val.0);
+    let val = val.set_with("with");
+    assert_eq!("with", val.0);
+}
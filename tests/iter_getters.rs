@@ -0,0 +1,161 @@
+#[macro_use]
+extern crate getset;
+
+use std::collections::{BTreeSet, HashMap};
+
+use crate::submodule::other::{Generic, Plain, Where};
+
+// For testing `pub(super)`
+mod submodule {
+    // For testing `pub(in super::other)`
+    pub mod other {
+        use std::collections::{BTreeSet, HashMap};
+
+        #[derive(Getters, MutGetters, Default)]
+        #[getset(get_iter, get_iter_mut)]
+        pub struct Plain {
+            /// A doc comment.
+            /// Multiple lines, even.
+            pub private_accessible: Vec<usize>,
+
+            /// A doc comment.
+            #[getset(get_iter = "pub", get_iter_mut = "pub")]
+            pub public_accessible: Vec<usize>,
+
+            #[getset(get_iter = "pub")]
+            pub set: BTreeSet<usize>,
+
+            #[getset(get_iter = "pub", get_iter_mut = "pub")]
+            pub map: HashMap<String, usize>,
+
+            // Prefixed getter.
+            #[getset(get_iter = "pub with_prefix", get_iter_mut = "pub with_prefix")]
+            pub public_prefixed: Vec<usize>,
+        }
+
+        #[derive(Getters, MutGetters, Default)]
+        #[getset(get_iter, get_iter_mut)]
+        pub struct Generic<T> {
+            /// A doc comment.
+            /// Multiple lines, even.
+            pub private_accessible: Vec<T>,
+
+            /// A doc comment.
+            #[getset(get_iter = "pub", get_iter_mut = "pub")]
+            pub public_accessible: Vec<T>,
+        }
+
+        #[derive(Getters, MutGetters, Default)]
+        #[getset(get_iter, get_iter_mut)]
+        pub struct Where<T>
+        where
+            T: Clone,
+        {
+            /// A doc comment.
+            /// Multiple lines, even.
+            pub private_accessible: Vec<T>,
+
+            /// A doc comment.
+            #[getset(get_iter = "pub", get_iter_mut = "pub")]
+            pub public_accessible: Vec<T>,
+        }
+
+        #[test]
+        fn test_plain() {
+            let mut val = Plain {
+                private_accessible: vec![1, 2],
+                ..Plain::default()
+            };
+            val.private_accessible_iter_mut().for_each(|v| *v += 1);
+            assert_eq!(5, val.private_accessible_iter().sum::<usize>());
+        }
+
+        #[test]
+        fn test_generic() {
+            let val = Generic {
+                private_accessible: vec![1, 2],
+                public_accessible: vec![],
+            };
+            assert_eq!(2, val.private_accessible_iter().count());
+        }
+
+        #[test]
+        fn test_where() {
+            let val = Where {
+                private_accessible: vec![1, 2],
+                public_accessible: vec![],
+            };
+            assert_eq!(2, val.private_accessible_iter().count());
+        }
+    }
+}
+
+#[test]
+fn test_plain() {
+    let mut val = Plain {
+        public_accessible: vec![1, 2, 3],
+        ..Plain::default()
+    };
+    for v in val.public_accessible_iter_mut() {
+        *v *= 2;
+    }
+    let doubled: Vec<&usize> = val.public_accessible_iter().collect();
+    assert_eq!(vec![&2, &4, &6], doubled);
+}
+
+#[test]
+fn test_other_collections() {
+    let mut val = Plain {
+        set: BTreeSet::from([3, 1, 2]),
+        map: HashMap::from([("one".to_string(), 1)]),
+        ..Plain::default()
+    };
+    assert_eq!(vec![&1, &2, &3], val.set_iter().collect::<Vec<_>>());
+    for (_, v) in val.map_iter_mut() {
+        *v += 1;
+    }
+    assert_eq!(
+        vec![(&"one".to_string(), &2)],
+        val.map_iter().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_generic() {
+    let mut val = Generic {
+        private_accessible: vec![],
+        public_accessible: vec!["a".to_string()],
+    };
+    val.public_accessible_iter_mut().for_each(|v| v.push('b'));
+    assert_eq!(vec!["ab"], val.public_accessible_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn test_where() {
+    let mut val = Where {
+        private_accessible: vec![],
+        public_accessible: vec!["a".to_string()],
+    };
+    val.public_accessible_iter_mut().for_each(|v| v.push('b'));
+    assert_eq!(vec!["ab"], val.public_accessible_iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn test_prefixed_plain() {
+    let mut val = Plain {
+        public_prefixed: vec![1],
+        ..Plain::default()
+    };
+    val.get_public_prefixed_iter_mut().for_each(|v| *v += 1);
+    assert_eq!(Some(&2), val.get_public_prefixed_iter().next());
+}
+
+#[test]
+fn test_unary_tuple() {
+    #[derive(Getters, MutGetters)]
+    struct Items(#[getset(get_iter, get_iter_mut)] Vec<u8>);
+
+    let mut val = Items(vec![1, 2]);
+    val.iter_mut().for_each(|v| *v += 1);
+    assert_eq!(vec![&2, &3], val.iter().collect::<Vec<_>>());
+}
@@ -0,0 +1,66 @@
+#[macro_use]
+extern crate getset;
+
+use crate::submodule::other::Plain;
+
+// For testing `pub(super)`
+mod submodule {
+    // For testing `pub(in super::other)`
+    pub mod other {
+        #[derive(CopyGetters, Getters, MutGetters, Setters, WithSetters, Default)]
+        #[getset(get_mut(inline), set(inline = "never"), set_with())]
+        pub struct Plain {
+            /// A doc comment.
+            /// Multiple lines, even.
+            #[getset(get_copy(prefix))]
+            private_accessible: usize,
+
+            /// A doc comment.
+            #[getset(
+                get_copy(vis = "pub", prefix, inline = "never"),
+                get_mut(vis = "pub(crate)", inline),
+                set(vis = "pub", inline = "always"),
+                set_with(vis = "pub")
+            )]
+            public_accessible: usize,
+
+            #[getset(
+                get(vis = "pub", prefix, inline = "never", as_ref = "str"),
+                set(vis = "pub", into, inline)
+            )]
+            name: String,
+
+            #[getset(
+                get_option(vis = "pub", prefix, or_default),
+                set(vis = "pub", strip_option)
+            )]
+            limit: Option<usize>,
+        }
+
+        #[test]
+        fn test_plain() {
+            let mut val = Plain::default();
+            val.set_private_accessible(1);
+            *val.private_accessible_mut() += 1;
+            assert_eq!(2, val.get_private_accessible());
+        }
+    }
+}
+
+#[test]
+fn test_plain() {
+    let mut val = Plain::default();
+    val.set_public_accessible(1);
+    *val.public_accessible_mut() += 1;
+    let val = val.with_public_accessible(3);
+    assert_eq!(3, val.get_public_accessible());
+}
+
+#[test]
+fn test_mode_options() {
+    let mut val = Plain::default();
+    val.set_name("name").set_limit(3);
+    assert_eq!("name", val.get_name());
+    assert_eq!(Some(&3), val.get_limit());
+    assert_eq!(3, val.get_limit_or_default());
+}
@@ -0,0 +1,92 @@
+#[macro_use]
+extern crate getset;
+
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread;
+
+#[derive(LockAccessors, Default)]
+#[lock]
+struct Locks {
+    /// A doc comment.
+    /// Multiple lines, even.
+    counter: Mutex<usize>,
+
+    #[getset(lock = "pub")]
+    shared: RwLock<String>,
+
+    #[getset(lock(vis = "pub", poison = "recover"))]
+    recovered: Mutex<usize>,
+
+    #[getset(lock(vis = "pub", poison = "result"))]
+    checked: RwLock<usize>,
+}
+
+// Poisons the locks of the `recovered` and `checked` fields.
+fn poisoned() -> Arc<Locks> {
+    let val = Arc::new(Locks::default());
+    let cloned = Arc::clone(&val);
+    let _ = thread::spawn(move || {
+        let _recovered = cloned.lock_recovered();
+        let _checked = cloned.write_checked().unwrap();
+        panic!("poisoning the locks");
+    })
+    .join();
+    val
+}
+
+#[test]
+fn test_plain() {
+    let val = Locks::default();
+    *val.lock_counter() = 2;
+    val.write_shared().push_str("value");
+    assert_eq!(2, *val.lock_counter());
+    assert_eq!("value", *val.read_shared());
+}
+
+#[test]
+fn test_recover() {
+    let val = poisoned();
+    *val.lock_recovered() += 1;
+    assert_eq!(1, *val.lock_recovered());
+}
+
+#[test]
+fn test_result() {
+    let val = Locks::default();
+    *val.write_checked().unwrap() = 1;
+    assert_eq!(1, *val.read_checked().unwrap());
+
+    let val = poisoned();
+    assert!(val.read_checked().is_err());
+    assert!(val.write_checked().is_err());
+}
+
+#[test]
+#[should_panic]
+fn test_panic() {
+    let val = Arc::new(Locks::default());
+    let cloned = Arc::clone(&val);
+    let _ = thread::spawn(move || {
+        let _guard = cloned.lock_counter();
+        panic!("poisoning the lock");
+    })
+    .join();
+    let _guard = val.lock_counter();
+}
+
+#[test]
+fn test_unary_tuple() {
+    #[derive(LockAccessors)]
+    struct Counter(#[getset(lock)] Mutex<u32>);
+
+    #[derive(LockAccessors)]
+    struct Config(#[getset(lock)] RwLock<String>);
+
+    let val = Counter(Mutex::new(1));
+    *val.lock() += 1;
+    assert_eq!(2, *val.lock());
+
+    let val = Config(RwLock::new(String::new()));
+    val.write().push('a');
+    assert_eq!("a", *val.read());
+}
@@ -0,0 +1,132 @@
+#[macro_use]
+extern crate getset;
+
+use std::hash::RandomState;
+
+use crate::submodule::other::{Generic, Plain, Where};
+
+// For testing `pub(super)`
+mod submodule {
+    // For testing `pub(in super::other)`
+    pub mod other {
+        use std::collections::{BTreeMap, HashMap};
+        use std::hash::Hash;
+
+        #[derive(CollectionAccessors, Default)]
+        #[collection]
+        pub struct Plain {
+            /// A doc comment.
+            /// Multiple lines, even.
+            private_accessible: HashMap<String, usize>,
+
+            /// A doc comment.
+            #[collection = "pub"]
+            public_accessible: HashMap<String, usize>,
+
+            #[getset(collection = "pub")]
+            sorted: BTreeMap<String, usize>,
+        }
+
+        #[derive(CollectionAccessors, Default)]
+        #[collection]
+        pub struct Generic<K: Hash + Eq, V, S> {
+            /// A doc comment.
+            /// Multiple lines, even.
+            private_accessible: HashMap<K, V, S>,
+
+            /// A doc comment.
+            #[collection = "pub"]
+            public_accessible: HashMap<K, V, S>,
+        }
+
+        #[derive(CollectionAccessors, Default)]
+        #[collection]
+        pub struct Where<K, V>
+        where
+            K: Ord,
+        {
+            /// A doc comment.
+            /// Multiple lines, even.
+            private_accessible: BTreeMap<K, V>,
+
+            /// A doc comment.
+            #[collection = "pub"]
+            public_accessible: BTreeMap<K, V>,
+        }
+
+        #[test]
+        fn test_plain() {
+            let mut val = Plain::default();
+            val.insert_private_accessible("one".to_string(), 1);
+            assert_eq!(Some(&1), val.private_accessible_get("one"));
+        }
+
+        #[test]
+        fn test_generic() {
+            let mut val = Generic::<usize, usize, std::hash::RandomState>::default();
+            val.insert_private_accessible(1, 1);
+            assert!(val.contains_private_accessible(&1));
+        }
+
+        #[test]
+        fn test_where() {
+            let mut val = Where::<usize, usize>::default();
+            val.insert_private_accessible(1, 1);
+            assert!(val.contains_private_accessible(&1));
+        }
+    }
+}
+
+#[test]
+fn test_plain() {
+    let mut val = Plain::default();
+    assert_eq!(None, val.insert_public_accessible("one".to_string(), 1));
+    assert_eq!(Some(1), val.insert_public_accessible("one".to_string(), 2));
+    // Keys are looked up through their borrowed form.
+    assert!(val.contains_public_accessible("one"));
+    assert_eq!(Some(&2), val.public_accessible_get("one"));
+    assert_eq!(Some(2), val.remove_public_accessible("one"));
+    assert_eq!(None, val.remove_public_accessible("one"));
+    assert!(!val.contains_public_accessible("one"));
+}
+
+#[test]
+fn test_btree_map() {
+    let mut val = Plain::default();
+    val.insert_sorted("b".to_string(), 2);
+    val.insert_sorted("a".to_string(), 1);
+    assert_eq!(Some(&1), val.sorted_get("a"));
+    assert_eq!(Some(2), val.remove_sorted("b"));
+    assert!(!val.contains_sorted("b"));
+}
+
+#[test]
+fn test_generic() {
+    let mut val = Generic::<String, usize, RandomState>::default();
+    val.insert_public_accessible("one".to_string(), 1);
+    assert_eq!(Some(&1), val.public_accessible_get("one"));
+    assert_eq!(Some(1), val.remove_public_accessible("one"));
+}
+
+#[test]
+fn test_where() {
+    let mut val = Where::<String, usize>::default();
+    val.insert_public_accessible("one".to_string(), 1);
+    assert_eq!(Some(&1), val.public_accessible_get("one"));
+    assert_eq!(Some(1), val.remove_public_accessible("one"));
+}
+
+#[test]
+fn test_unary_tuple() {
+    use std::collections::HashMap;
+
+    #[derive(CollectionAccessors, Getters, Default)]
+    struct Registry(#[getset(collection, get)] HashMap<String, u8>);
+
+    let mut val = Registry::default();
+    val.insert("one".to_string(), 1);
+    assert!(val.contains("one"));
+    assert_eq!(Some(&1), val.lookup("one"));
+    assert_eq!(1, val.get().len());
+    assert_eq!(Some(1), val.remove("one"));
+}
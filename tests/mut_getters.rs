@@ -17,17 +17,18 @@ mod submodule {
             /// A doc comment.
             #[get_mut = "pub"]
             public_accessible: usize,
-            // /// A doc comment.
-            // #[get_mut = "pub(crate)"]
-            // crate_accessible: usize,
 
-            // /// A doc comment.
-            // #[get_mut = "pub(super)"]
-            // super_accessible: usize,
+            /// A doc comment.
+            #[get_mut = "pub(crate)"]
+            crate_accessible: usize,
+
+            /// A doc comment.
+            #[get_mut = "pub(super)"]
+            super_accessible: usize,
 
-            // /// A doc comment.
-            // #[get_mut = "pub(in super::other)"]
-            // scope_accessible: usize,
+            /// A doc comment.
+            #[get_mut = "pub(in super::other)"]
+            scope_accessible: usize,
 
             // Prefixed getter.
             #[get_mut = "with_prefix"]
@@ -48,17 +49,18 @@ mod submodule {
             /// A doc comment.
             #[get_mut = "pub"]
             public_accessible: T,
-            // /// A doc comment.
-            // #[get_mut = "pub(crate)"]
-            // crate_accessible: usize,
 
-            // /// A doc comment.
-            // #[get_mut = "pub(super)"]
-            // super_accessible: usize,
+            /// A doc comment.
+            #[get_mut = "pub(crate)"]
+            crate_accessible: usize,
+
+            /// A doc comment.
+            #[get_mut = "pub(super)"]
+            super_accessible: usize,
 
-            // /// A doc comment.
-            // #[get_mut = "pub(in super::other)"]
-            // scope_accessible: usize,
+            /// A doc comment.
+            #[get_mut = "pub(in super::other)"]
+            scope_accessible: usize,
 
             // Prefixed getter.
             #[get_mut = "with_prefix"]
@@ -82,17 +84,18 @@ mod submodule {
             /// A doc comment.
             #[get_mut = "pub"]
             public_accessible: T,
-            // /// A doc comment.
-            // #[get_mut = "pub(crate)"]
-            // crate_accessible: usize,
 
-            // /// A doc comment.
-            // #[get_mut = "pub(super)"]
-            // super_accessible: usize,
+            /// A doc comment.
+            #[get_mut = "pub(crate)"]
+            crate_accessible: usize,
+
+            /// A doc comment.
+            #[get_mut = "pub(super)"]
+            super_accessible: usize,
 
-            // /// A doc comment.
-            // #[get_mut = "pub(in super::other)"]
-            // scope_accessible: usize,
+            /// A doc comment.
+            #[get_mut = "pub(in super::other)"]
+            scope_accessible: usize,
 
             // Prefixed getter.
             #[get_mut = "with_prefix"]
@@ -107,6 +110,9 @@ mod submodule {
         fn test_plain() {
             let mut val = Plain::default();
             (*val.private_accessible_mut()) += 1;
+            (*val.crate_accessible_mut()) += 1;
+            (*val.super_accessible_mut()) += 1;
+            (*val.scope_accessible_mut()) += 1;
             (*val.get_private_prefixed_mut()) += 1;
         }
 
@@ -114,6 +120,9 @@ mod submodule {
         fn test_generic() {
             let mut val = Generic::<usize>::default();
             (*val.private_accessible_mut()) += 1;
+            (*val.crate_accessible_mut()) += 1;
+            (*val.super_accessible_mut()) += 1;
+            (*val.scope_accessible_mut()) += 1;
             (*val.get_private_prefixed_mut()) += 1;
         }
 
@@ -121,6 +130,9 @@ mod submodule {
         fn test_where() {
             let mut val = Where::<usize>::default();
             (*val.private_accessible_mut()) += 1;
+            (*val.crate_accessible_mut()) += 1;
+            (*val.super_accessible_mut()) += 1;
+            (*val.scope_accessible_mut()) += 1;
             (*val.get_private_prefixed_mut()) += 1;
         }
     }
@@ -0,0 +1,201 @@
+#[macro_use]
+extern crate getset;
+
+use crate::submodule::other::{Generic, Plain, Where};
+
+// For testing `pub(super)`
+mod submodule {
+    // For testing `pub(in super::other)`
+    pub mod other {
+        #[derive(Getters)]
+        #[get_option]
+        pub struct Plain {
+            /// A doc comment.
+            /// Multiple lines, even.
+            private_accessible: Option<usize>,
+
+            /// A doc comment.
+            #[get_option = "pub"]
+            public_accessible: Option<usize>,
+
+            #[get_option = "pub or_default or"]
+            missing: Option<String>,
+
+            #[get_option = "pub"]
+            result: Result<usize, String>,
+
+            #[get_option = "pub or_default or"]
+            failed: Result<usize, String>,
+
+            // Prefixed getter.
+            #[get_option = "with_prefix"]
+            private_prefixed: Option<usize>,
+
+            // Prefixed getter.
+            #[get_option = "pub with_prefix or_default"]
+            public_prefixed: Option<usize>,
+        }
+
+        impl Default for Plain {
+            fn default() -> Plain {
+                Plain {
+                    private_accessible: Some(17),
+                    public_accessible: Some(18),
+                    missing: None,
+                    result: Ok(21),
+                    failed: Err("failed".to_string()),
+                    private_prefixed: Some(19),
+                    public_prefixed: Some(20),
+                }
+            }
+        }
+
+        #[derive(Getters, Default)]
+        #[get_option]
+        pub struct Generic<T: Clone + Default> {
+            /// A doc comment.
+            /// Multiple lines, even.
+            private_accessible: Option<T>,
+
+            /// A doc comment.
+            #[get_option = "pub or_default"]
+            public_accessible: Option<T>,
+        }
+
+        #[derive(Getters, Default)]
+        #[get_option]
+        pub struct Where<T>
+        where
+            T: Clone + Default,
+        {
+            /// A doc comment.
+            /// Multiple lines, even.
+            private_accessible: Option<T>,
+
+            /// A doc comment.
+            #[get_option = "pub or_default"]
+            public_accessible: Option<T>,
+        }
+
+        #[test]
+        fn test_plain() {
+            let val = Plain::default();
+            assert_eq!(Some(&17), val.private_accessible());
+        }
+
+        #[test]
+        fn test_generic() {
+            let val = Generic::<usize>::default();
+            assert_eq!(None, val.private_accessible());
+        }
+
+        #[test]
+        fn test_where() {
+            let val = Where::<usize>::default();
+            assert_eq!(None, val.private_accessible());
+        }
+
+        #[test]
+        fn test_prefixed_plain() {
+            let val = Plain::default();
+            assert_eq!(Some(&19), val.get_private_prefixed());
+        }
+    }
+}
+
+#[test]
+fn test_plain() {
+    let val = Plain::default();
+    assert_eq!(Some(&18), val.public_accessible());
+}
+
+#[test]
+fn test_or_default() {
+    let val = Plain::default();
+    assert_eq!(None, val.missing());
+    assert_eq!(String::new(), val.missing_or_default());
+    assert_eq!(0, val.failed_or_default());
+}
+
+#[test]
+fn test_or() {
+    let val = Plain::default();
+    let fallback = "fallback".to_string();
+    assert_eq!("fallback", val.missing_or(&fallback));
+    assert_eq!(&3, val.failed_or(&3));
+}
+
+#[test]
+fn test_result() {
+    let val = Plain::default();
+    assert_eq!(Ok(&21), val.result());
+    assert_eq!(Err(&"failed".to_string()), val.failed());
+}
+
+#[test]
+fn test_generic() {
+    let val = Generic::<usize>::default();
+    assert_eq!(None, val.public_accessible());
+    assert_eq!(usize::default(), val.public_accessible_or_default());
+}
+
+#[test]
+fn test_where() {
+    let val = Where::<usize>::default();
+    assert_eq!(None, val.public_accessible());
+    assert_eq!(usize::default(), val.public_accessible_or_default());
+}
+
+#[test]
+fn test_prefixed_plain() {
+    let val = Plain::default();
+    assert_eq!(Some(&20), val.get_public_prefixed());
+    assert_eq!(20, val.get_public_prefixed_or_default());
+}
+
+#[test]
+fn test_unary_tuple() {
+    #[derive(Getters)]
+    struct Maybe(#[getset(get_option = "or_default")] Option<usize>);
+
+    let val = Maybe(Some(42));
+    assert_eq!(Some(&42), val.get());
+    assert_eq!(42, val.get_or_default());
+}
+
+#[test]
+fn test_overriding_struct_get() {
+    #[derive(Getters)]
+    #[getset(get = "pub")]
+    struct Both {
+        plain: Option<usize>,
+        #[getset(get_option = "pub")]
+        optional: Option<usize>,
+    }
+
+    let val = Both {
+        plain: Some(1),
+        optional: Some(2),
+    };
+    assert_eq!(&Some(1), val.plain());
+    assert_eq!(Some(&2), val.optional());
+}
+
+#[test]
+fn test_or_default_on_struct() {
+    struct NoDefault;
+
+    #[derive(Getters)]
+    #[getset(get_option = "or_default")]
+    struct Mixed {
+        count: Option<usize>,
+        other: Option<NoDefault>,
+    }
+
+    let val = Mixed {
+        count: None,
+        other: Some(NoDefault),
+    };
+    assert_eq!(0, val.count_or_default());
+    assert!(val.other().is_some());
+}
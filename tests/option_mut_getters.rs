@@ -0,0 +1,164 @@
+#[macro_use]
+extern crate getset;
+
+use crate::submodule::other::{Generic, Plain, Where};
+
+// For testing `pub(super)`
+mod submodule {
+    // For testing `pub(in super::other)`
+    pub mod other {
+        #[derive(MutGetters)]
+        #[getset(get_option_mut)]
+        pub struct Plain {
+            /// A doc comment.
+            /// Multiple lines, even.
+            private_accessible: Option<usize>,
+
+            /// A doc comment.
+            #[getset(get_option_mut = "pub")]
+            public_accessible: Option<usize>,
+
+            #[getset(get_option_mut = "pub")]
+            result: Result<usize, String>,
+
+            // Prefixed getter.
+            #[getset(get_option_mut = "pub with_prefix")]
+            public_prefixed: Option<usize>,
+        }
+
+        impl Default for Plain {
+            fn default() -> Plain {
+                Plain {
+                    private_accessible: None,
+                    public_accessible: Some(18),
+                    result: Err("failed".to_string()),
+                    public_prefixed: None,
+                }
+            }
+        }
+
+        #[derive(MutGetters, Default)]
+        #[getset(get_option_mut)]
+        pub struct Generic<T: Default> {
+            /// A doc comment.
+            /// Multiple lines, even.
+            private_accessible: Option<T>,
+
+            /// A doc comment.
+            #[getset(get_option_mut = "pub")]
+            public_accessible: Option<T>,
+        }
+
+        #[derive(MutGetters, Default)]
+        #[getset(get_option_mut)]
+        pub struct Where<T>
+        where
+            T: Default,
+        {
+            /// A doc comment.
+            /// Multiple lines, even.
+            private_accessible: Option<T>,
+
+            /// A doc comment.
+            #[getset(get_option_mut = "pub")]
+            public_accessible: Option<T>,
+        }
+
+        #[test]
+        fn test_plain() {
+            let mut val = Plain::default();
+            assert_eq!(None, val.private_accessible_mut());
+            *val.private_accessible_get_or_insert_with(|| 1) += 1;
+            assert_eq!(Some(&mut 2), val.private_accessible_mut());
+        }
+
+        #[test]
+        fn test_generic() {
+            let mut val = Generic::<usize>::default();
+            assert_eq!(None, val.private_accessible_mut());
+        }
+
+        #[test]
+        fn test_where() {
+            let mut val = Where::<usize>::default();
+            assert_eq!(None, val.private_accessible_mut());
+        }
+    }
+}
+
+#[test]
+fn test_plain() {
+    let mut val = Plain::default();
+    if let Some(public) = val.public_accessible_mut() {
+        *public += 1;
+    }
+    assert_eq!(Some(&mut 19), val.public_accessible_mut());
+}
+
+#[test]
+fn test_get_or_insert_with() {
+    let mut val = Plain::default();
+    assert_eq!(&mut 18, val.public_accessible_get_or_insert_with(|| 0));
+    *val.public_accessible_get_or_insert_with(|| 0) += 1;
+    assert_eq!(Some(&mut 19), val.public_accessible_mut());
+}
+
+#[test]
+fn test_result() {
+    let mut val = Plain::default();
+    if let Err(error) = val.result_mut() {
+        error.push_str(" twice");
+    }
+    assert_eq!(Err(&mut "failed twice".to_string()), val.result_mut());
+}
+
+#[test]
+fn test_generic() {
+    let mut val = Generic::<usize>::default();
+    *val.public_accessible_get_or_insert_with(Default::default) += 1;
+    assert_eq!(Some(&mut 1), val.public_accessible_mut());
+}
+
+#[test]
+fn test_where() {
+    let mut val = Where::<usize>::default();
+    *val.public_accessible_get_or_insert_with(Default::default) += 1;
+    assert_eq!(Some(&mut 1), val.public_accessible_mut());
+}
+
+#[test]
+fn test_prefixed_plain() {
+    let mut val = Plain::default();
+    *val.get_public_prefixed_get_or_insert_with(|| 20) += 1;
+    assert_eq!(Some(&mut 21), val.get_public_prefixed_mut());
+}
+
+#[test]
+fn test_unary_tuple() {
+    #[derive(MutGetters)]
+    struct Maybe(#[getset(get_option_mut)] Option<usize>);
+
+    let mut val = Maybe(None);
+    assert_eq!(None, val.get_mut());
+    *val.get_or_insert_with(|| 41) += 1;
+    assert_eq!(Some(&mut 42), val.get_mut());
+}
+
+#[test]
+fn test_overriding_struct_get_mut() {
+    #[derive(MutGetters)]
+    #[getset(get_mut = "pub")]
+    struct Both {
+        plain: Option<usize>,
+        #[getset(get_option_mut = "pub")]
+        optional: Option<usize>,
+    }
+
+    let mut val = Both {
+        plain: None,
+        optional: Some(2),
+    };
+    *val.plain_mut() = Some(1);
+    assert_eq!(&mut Some(1), val.plain_mut());
+    assert_eq!(Some(&mut 2), val.optional_mut());
+}
@@ -0,0 +1,191 @@
+#[macro_use]
+extern crate getset;
+
+use std::future::{self, Future};
+use std::marker::PhantomPinned;
+use std::pin::{Pin, pin};
+use std::task::{Context, Poll, Waker};
+
+use crate::submodule::other::{Generic, Plain, Where};
+
+// For testing `pub(super)`
+mod submodule {
+    // For testing `pub(in super::other)`
+    pub mod other {
+        #[derive(PinProjections, Default)]
+        #[pin]
+        pub struct Plain {
+            /// A doc comment.
+            /// Multiple lines, even.
+            private_accessible: usize,
+
+            /// A doc comment.
+            #[pin = "pub"]
+            pub public_accessible: String,
+
+            #[getset(unpin = "pub")]
+            pub unpinned: usize,
+
+            #[getset(skip)]
+            pub skipped: usize,
+        }
+
+        #[derive(PinProjections, Default)]
+        #[unpin]
+        pub struct Generic<T> {
+            /// A doc comment.
+            /// Multiple lines, even.
+            private_accessible: usize,
+
+            /// A doc comment.
+            #[pin = "pub"]
+            pub public_accessible: T,
+        }
+
+        #[derive(PinProjections, Default)]
+        #[unpin]
+        pub struct Where<T>
+        where
+            T: Clone,
+        {
+            /// A doc comment.
+            /// Multiple lines, even.
+            private_accessible: usize,
+
+            /// A doc comment.
+            #[pin = "pub"]
+            pub public_accessible: T,
+        }
+
+        #[test]
+        fn test_plain() {
+            let mut val = Box::pin(Plain::default());
+            *val.as_mut().private_accessible_pin_mut() = 1;
+            assert_eq!(1, val.private_accessible);
+        }
+
+        #[test]
+        fn test_generic() {
+            let mut val = Box::pin(Generic::<usize>::default());
+            *val.as_mut().private_accessible_mut() = 1;
+            assert_eq!(1, val.private_accessible);
+        }
+
+        #[test]
+        fn test_where() {
+            let mut val = Box::pin(Where::<usize>::default());
+            *val.as_mut().private_accessible_mut() = 1;
+            assert_eq!(1, val.private_accessible);
+        }
+    }
+}
+
+#[derive(PinProjections)]
+struct Counted<F> {
+    #[getset(pin)]
+    inner: F,
+    #[getset(unpin)]
+    polls: usize,
+}
+
+impl<F: Future> Future for Counted<F> {
+    type Output = (F::Output, usize);
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        *self.as_mut().polls_mut() += 1;
+        match self.as_mut().inner_pin_mut().poll(cx) {
+            Poll::Ready(output) => Poll::Ready((output, *self.polls_mut())),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Pending on its first poll, like a future waiting on I/O.
+#[derive(Default)]
+struct Yield {
+    yielded: bool,
+    _pinned: PhantomPinned,
+}
+
+impl Future for Yield {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        // SAFETY: `yielded` is not structurally pinned.
+        let this = unsafe { self.get_unchecked_mut() };
+        if this.yielded {
+            Poll::Ready(())
+        } else {
+            this.yielded = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+fn assert_unpin<T: Unpin>() {}
+
+#[test]
+fn test_plain() {
+    let mut val = pin!(Plain::default());
+    val.as_mut().public_accessible_pin_mut().push('a');
+    *val.as_mut().unpinned_mut() = 2;
+    assert_eq!("a", val.public_accessible);
+    assert_eq!(2, val.unpinned);
+    assert_eq!(0, val.skipped);
+}
+
+#[test]
+fn test_future() {
+    let mut cx = Context::from_waker(Waker::noop());
+
+    let mut ready = pin!(Counted {
+        inner: future::ready(1),
+        polls: 0,
+    });
+    assert_eq!(Poll::Ready((1, 1)), ready.as_mut().poll(&mut cx));
+
+    let mut pending = pin!(Counted {
+        inner: Yield::default(),
+        polls: 0,
+    });
+    assert_eq!(Poll::Pending, pending.as_mut().poll(&mut cx));
+    assert_eq!(Poll::Ready(((), 2)), pending.as_mut().poll(&mut cx));
+}
+
+#[test]
+fn test_unpin() {
+    assert_unpin::<Counted<future::Ready<u8>>>();
+    assert_unpin::<Generic<String>>();
+}
+
+#[test]
+fn test_generic() {
+    let mut val = Box::pin(Generic::<String>::default());
+    val.as_mut().public_accessible_pin_mut().push('a');
+    assert_eq!("a", val.public_accessible);
+}
+
+#[test]
+fn test_where() {
+    let mut val = Box::pin(Where::<String>::default());
+    val.as_mut().public_accessible_pin_mut().push('a');
+    assert_eq!("a", val.public_accessible);
+}
+
+#[test]
+fn test_unary_tuple() {
+    #[derive(PinProjections)]
+    struct Wrapper<F>(#[getset(pin)] F);
+
+    #[derive(PinProjections)]
+    struct Count(#[getset(unpin)] usize);
+
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut val = pin!(Wrapper(future::ready(1)));
+    assert_eq!(Poll::Ready(1), val.as_mut().pin_mut().poll(&mut cx));
+
+    let mut val = pin!(Count(1));
+    *val.as_mut().unpin_mut() += 1;
+    assert_eq!(2, val.0);
+}
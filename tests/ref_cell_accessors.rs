@@ -0,0 +1,145 @@
+#[macro_use]
+extern crate getset;
+
+use std::cell::RefCell;
+
+use crate::submodule::other::{Generic, Plain, Where};
+
+// For testing `pub(super)`
+mod submodule {
+    // For testing `pub(in super::other)`
+    pub mod other {
+        use std::cell::RefCell;
+
+        #[derive(CellAccessors, Default)]
+        #[ref_cell]
+        pub struct Plain {
+            /// A doc comment.
+            /// Multiple lines, even.
+            private_accessible: RefCell<Vec<usize>>,
+
+            /// A doc comment.
+            #[ref_cell = "pub"]
+            public_accessible: RefCell<Vec<usize>>,
+
+            #[getset(ref_cell(vis = "pub", policy = "both"))]
+            fallible: RefCell<String>,
+
+            #[getset(ref_cell(vis = "pub", policy = "try"))]
+            checked: RefCell<String>,
+        }
+
+        #[derive(CellAccessors, Default)]
+        #[ref_cell]
+        pub struct Generic<T> {
+            /// A doc comment.
+            /// Multiple lines, even.
+            private_accessible: RefCell<T>,
+
+            /// A doc comment.
+            #[ref_cell = "pub"]
+            public_accessible: RefCell<T>,
+        }
+
+        #[derive(CellAccessors, Default)]
+        #[ref_cell]
+        pub struct Where<T>
+        where
+            T: Clone,
+        {
+            /// A doc comment.
+            /// Multiple lines, even.
+            private_accessible: RefCell<T>,
+
+            /// A doc comment.
+            #[ref_cell = "pub"]
+            public_accessible: RefCell<T>,
+        }
+
+        #[test]
+        fn test_plain() {
+            let val = Plain::default();
+            val.private_accessible_mut().push(1);
+            assert_eq!(vec![1], *val.private_accessible());
+        }
+
+        #[test]
+        fn test_generic() {
+            let val = Generic::<usize>::default();
+            *val.private_accessible_mut() = 1;
+            assert_eq!(1, *val.private_accessible());
+        }
+
+        #[test]
+        fn test_where() {
+            let val = Where::<usize>::default();
+            *val.private_accessible_mut() = 1;
+            assert_eq!(1, *val.private_accessible());
+        }
+    }
+}
+
+#[test]
+fn test_plain() {
+    let val = Plain::default();
+    val.public_accessible_mut().extend([1, 2]);
+    assert_eq!(vec![1, 2], *val.public_accessible());
+}
+
+#[test]
+#[should_panic]
+fn test_conflicting_borrow() {
+    let val = Plain::default();
+    let _guard = val.public_accessible_mut();
+    let _ = val.public_accessible();
+}
+
+#[test]
+fn test_try() {
+    let val = Plain::default();
+    val.try_fallible_mut().unwrap().push_str("value");
+    {
+        let _guard = val.fallible();
+        assert!(val.try_fallible_mut().is_err());
+        assert_eq!("value", *val.try_fallible().unwrap());
+    }
+    {
+        let _guard = val.fallible_mut();
+        assert!(val.try_fallible().is_err());
+    }
+    assert!(val.try_fallible().is_ok());
+}
+
+#[test]
+fn test_try_policy() {
+    let val = Plain::default();
+    val.try_checked_mut().unwrap().push_str("value");
+    let _guard = val.try_checked().unwrap();
+    assert!(val.try_checked_mut().is_err());
+}
+
+#[test]
+fn test_generic() {
+    let val = Generic::<String>::default();
+    val.public_accessible_mut().push('a');
+    assert_eq!("a", *val.public_accessible());
+}
+
+#[test]
+fn test_where() {
+    let val = Where::<String>::default();
+    val.public_accessible_mut().push('a');
+    assert_eq!("a", *val.public_accessible());
+}
+
+#[test]
+fn test_unary_tuple() {
+    #[derive(CellAccessors)]
+    struct Cache(#[getset(ref_cell(policy = "both"))] RefCell<Vec<u8>>);
+
+    let val = Cache(RefCell::new(vec![]));
+    val.borrow_mut().push(1);
+    assert_eq!(vec![1], *val.borrow());
+    let _guard = val.try_borrow_mut().unwrap();
+    assert!(val.try_borrow().is_err());
+}
@@ -0,0 +1,141 @@
+#[macro_use]
+extern crate getset;
+
+use crate::submodule::other::{Generic, Plain, Where};
+
+// For testing `pub(super)`
+mod submodule {
+    // For testing `pub(in super::other)`
+    pub mod other {
+        #[derive(Getters, MutGetters, Setters, Default)]
+        #[getset(rename_all = "camelCase", get, get_mut, set)]
+        pub struct Plain {
+            /// A doc comment.
+            /// Multiple lines, even.
+            private_accessible: usize,
+
+            /// A doc comment.
+            #[getset(get = "pub", get_mut = "pub", set = "pub")]
+            public_accessible: usize,
+
+            #[getset(rename_all = "PascalCase", get = "pub with_prefix")]
+            pascal_case: usize,
+
+            #[getset(rename_all = "SCREAMING_SNAKE_CASE", get = "pub")]
+            screaming: usize,
+
+            #[getset(rename_all = "snake_case", get = "pub", set = "pub")]
+            snake_case: usize,
+
+            #[getset(rename_all = "kebab-case", get = "pub", set = "pub")]
+            kebab_case: usize,
+
+            #[getset(rename_all = "SCREAMING-KEBAB-CASE", get = "pub")]
+            screaming_kebab: usize,
+
+            #[getset(get(vis = "pub", name = "kept_name"))]
+            named: usize,
+
+            #[getset(set(vis = "pub", strip_option))]
+            pub optional_label: Option<String>,
+        }
+
+        #[derive(Getters, Setters, Default)]
+        #[getset(rename_all = "PascalCase", get, set)]
+        pub struct Generic<T> {
+            /// A doc comment.
+            /// Multiple lines, even.
+            private_accessible: T,
+
+            /// A doc comment.
+            #[getset(get = "pub", set = "pub")]
+            public_accessible: T,
+        }
+
+        #[derive(Getters, Setters, Default)]
+        #[getset(rename_all = "PascalCase", get, set)]
+        pub struct Where<T>
+        where
+            T: Clone,
+        {
+            /// A doc comment.
+            /// Multiple lines, even.
+            private_accessible: T,
+
+            /// A doc comment.
+            #[getset(get = "pub", set = "pub")]
+            public_accessible: T,
+        }
+
+        #[test]
+        fn test_plain() {
+            let mut val = Plain::default();
+            val.setPrivateAccessible(1);
+            *val.privateAccessibleMut() += 1;
+            assert_eq!(2, *val.privateAccessible());
+        }
+
+        #[test]
+        fn test_generic() {
+            let mut val = Generic::<usize>::default();
+            val.SetPrivateAccessible(1);
+            assert_eq!(1, *val.PrivateAccessible());
+        }
+
+        #[test]
+        fn test_where() {
+            let mut val = Where::<usize>::default();
+            val.SetPrivateAccessible(1);
+            assert_eq!(1, *val.PrivateAccessible());
+        }
+    }
+}
+
+#[test]
+fn test_plain() {
+    let mut val = Plain::default();
+    val.setPublicAccessible(1);
+    *val.publicAccessibleMut() += 1;
+    assert_eq!(2, *val.publicAccessible());
+}
+
+#[test]
+fn test_field_overrides() {
+    let mut val = Plain::default();
+    val.set_snake_case(1);
+    assert_eq!(1, *val.snake_case());
+    assert_eq!(0, *val.GetPascalCase());
+    assert_eq!(0, *val.SCREAMING());
+    assert_eq!(0, *val.kept_name());
+}
+
+#[test]
+fn test_kebab_case() {
+    let mut val = Plain::default();
+    val.set_kebab_case(1);
+    assert_eq!(1, *val.kebab_case());
+    assert_eq!(0, *val.SCREAMING_KEBAB());
+}
+
+#[test]
+fn test_companions() {
+    let mut val = Plain::default();
+    val.setOptionalLabel("label".to_string());
+    assert_eq!(Some("label"), val.optional_label.as_deref());
+    val.clearOptionalLabel();
+    assert_eq!(None, val.optional_label);
+}
+
+#[test]
+fn test_generic() {
+    let mut val = Generic::<usize>::default();
+    val.SetPublicAccessible(1);
+    assert_eq!(1, *val.PublicAccessible());
+}
+
+#[test]
+fn test_where() {
+    let mut val = Where::<usize>::default();
+    val.SetPublicAccessible(1);
+    assert_eq!(1, *val.PublicAccessible());
+}
@@ -21,17 +21,18 @@ mod submodule {
             /// This field is used for testing chaining.
             #[set = "pub"]
             second_public_accessible: bool,
-            // /// A doc comment.
-            // #[set = "pub(crate)"]
-            // crate_accessible: usize,
 
-            // /// A doc comment.
-            // #[set = "pub(super)"]
-            // super_accessible: usize,
+            /// A doc comment.
+            #[set = "pub(crate)"]
+            crate_accessible: usize,
+
+            /// A doc comment.
+            #[set = "pub(super)"]
+            super_accessible: usize,
 
-            // /// A doc comment.
-            // #[set = "pub(in super::other)"]
-            // scope_accessible: usize,
+            /// A doc comment.
+            #[set = "pub(in super::other)"]
+            scope_accessible: usize,
         }
 
         #[derive(Setters, Default)]
@@ -44,17 +45,18 @@ mod submodule {
             /// A doc comment.
             #[set = "pub"]
             public_accessible: T,
-            // /// A doc comment.
-            // #[set = "pub(crate)"]
-            // crate_accessible: usize,
 
-            // /// A doc comment.
-            // #[set = "pub(super)"]
-            // super_accessible: usize,
+            /// A doc comment.
+            #[set = "pub(crate)"]
+            crate_accessible: usize,
+
+            /// A doc comment.
+            #[set = "pub(super)"]
+            super_accessible: usize,
 
-            // /// A doc comment.
-            // #[set = "pub(in super::other)"]
-            // scope_accessible: usize,
+            /// A doc comment.
+            #[set = "pub(in super::other)"]
+            scope_accessible: usize,
         }
 
         #[derive(Setters, Default)]
@@ -70,35 +72,45 @@ mod submodule {
             /// A doc comment.
             #[set = "pub"]
             public_accessible: T,
-            // /// A doc comment.
-            // #[set = "pub(crate)"]
-            // crate_accessible: usize,
 
-            // /// A doc comment.
-            // #[set = "pub(super)"]
-            // super_accessible: usize,
+            /// A doc comment.
+            #[set = "pub(crate)"]
+            crate_accessible: usize,
+
+            /// A doc comment.
+            #[set = "pub(super)"]
+            super_accessible: usize,
 
-            // /// A doc comment.
-            // #[set = "pub(in super::other)"]
-            // scope_accessible: usize,
+            /// A doc comment.
+            #[set = "pub(in super::other)"]
+            scope_accessible: usize,
         }
 
         #[test]
         fn test_plain() {
             let mut val = Plain::default();
             val.set_private_accessible(1);
+            val.set_crate_accessible(1);
+            val.set_super_accessible(1);
+            val.set_scope_accessible(1);
         }
 
         #[test]
         fn test_generic() {
             let mut val = Generic::default();
             val.set_private_accessible(1);
+            val.set_crate_accessible(1);
+            val.set_super_accessible(1);
+            val.set_scope_accessible(1);
         }
 
         #[test]
         fn test_where() {
             let mut val = Where::default();
             val.set_private_accessible(1);
+            val.set_crate_accessible(1);
+            val.set_super_accessible(1);
+            val.set_scope_accessible(1);
         }
     }
 }
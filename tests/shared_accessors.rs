@@ -0,0 +1,81 @@
+#[macro_use]
+extern crate getset;
+
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+#[derive(SharedAccessors, Default)]
+#[shared]
+struct Handles {
+    /// A doc comment.
+    /// Multiple lines, even.
+    name: Arc<String>,
+
+    #[shared = "pub make_mut"]
+    pub items: Arc<Vec<usize>>,
+
+    #[getset(shared(vis = "pub", make_mut))]
+    pub local: Rc<String>,
+
+    // Not `Clone`, so without `make_mut`.
+    #[getset(shared = "pub")]
+    pub state: Arc<Mutex<usize>>,
+}
+
+#[test]
+fn test_plain() {
+    let val = Handles::default();
+    let name: Arc<String> = val.name_shared();
+    assert_eq!("", val.name());
+    assert!(Arc::ptr_eq(&name, &val.name));
+
+    *val.state().lock().unwrap() = 1;
+    let state: Arc<Mutex<usize>> = val.state_shared();
+    assert_eq!(1, *state.lock().unwrap());
+    assert_eq!(2, Arc::strong_count(&val.state));
+}
+
+#[test]
+fn test_copy_on_write() {
+    let mut val = Handles::default();
+    val.items_make_mut().push(1);
+    let snapshot = val.items_shared();
+    val.items_make_mut().push(2);
+    assert_eq!(&[1], snapshot.as_slice());
+    assert_eq!(&[1, 2], val.items().as_slice());
+    assert!(!Arc::ptr_eq(&snapshot, &val.items));
+}
+
+#[test]
+fn test_rc() {
+    let mut val = Handles::default();
+    let snapshot: Rc<String> = val.local_shared();
+    val.local_make_mut().push('a');
+    assert_eq!("a", val.local());
+    assert_eq!("", *snapshot);
+}
+
+#[test]
+fn test_generic() {
+    #[derive(SharedAccessors, Default)]
+    #[shared = "make_mut"]
+    struct Snapshot<T: Clone> {
+        value: Rc<T>,
+    }
+
+    let mut val = Snapshot::<String>::default();
+    val.value_make_mut().push('a');
+    assert_eq!("a", val.value());
+}
+
+#[test]
+fn test_unary_tuple() {
+    #[derive(SharedAccessors)]
+    struct Config(#[getset(shared = "make_mut")] Arc<String>);
+
+    let mut val = Config(Arc::new("config".to_string()));
+    let snapshot = val.shared();
+    val.make_mut().push('!');
+    assert_eq!("config!", val.value());
+    assert_eq!("config", *snapshot);
+}
@@ -0,0 +1,156 @@
+#[macro_use]
+extern crate getset;
+
+use crate::submodule::other::{Generic, Plain, Where};
+
+// For testing `pub(super)`
+mod submodule {
+    // For testing `pub(in super::other)`
+    pub mod other {
+        #[derive(Setters, WithSetters, Default)]
+        #[getset(set = "strip_option", set_with = "strip_option")]
+        pub struct Plain {
+            /// A doc comment.
+            /// Multiple lines, even.
+            pub private_accessible: Option<usize>,
+
+            /// A doc comment.
+            #[getset(set = "pub strip_option", set_with = "pub strip_option")]
+            pub public_accessible: Option<usize>,
+
+            /// This field is used for testing chaining.
+            #[getset(set = "pub strip_option into", set_with = "pub strip_option into")]
+            pub second_public_accessible: Option<String>,
+
+            #[getset(set(vis = "pub", strip_option), set_with(vis = "pub", strip_option))]
+            pub listed: Option<bool>,
+        }
+
+        #[derive(Setters, WithSetters, Default)]
+        #[getset(set = "strip_option", set_with = "strip_option")]
+        pub struct Generic<T: Default> {
+            /// A doc comment.
+            /// Multiple lines, even.
+            pub private_accessible: Option<T>,
+
+            /// A doc comment.
+            #[getset(set = "pub strip_option", set_with = "pub strip_option")]
+            pub public_accessible: Option<T>,
+        }
+
+        #[derive(Setters, WithSetters, Default)]
+        #[getset(set = "strip_option", set_with = "strip_option")]
+        pub struct Where<T>
+        where
+            T: Default,
+        {
+            /// A doc comment.
+            /// Multiple lines, even.
+            pub private_accessible: Option<T>,
+
+            /// A doc comment.
+            #[getset(set = "pub strip_option", set_with = "pub strip_option")]
+            pub public_accessible: Option<T>,
+        }
+
+        #[test]
+        fn test_plain() {
+            let mut val = Plain::default();
+            val.set_private_accessible(1);
+            assert_eq!(Some(1), val.private_accessible);
+            val.clear_private_accessible();
+            assert_eq!(None, val.private_accessible);
+        }
+
+        #[test]
+        fn test_generic() {
+            let mut val = Generic::<usize>::default();
+            val.set_private_accessible(1);
+            assert_eq!(Some(1), val.private_accessible);
+        }
+
+        #[test]
+        fn test_where() {
+            let mut val = Where::<usize>::default();
+            val.set_private_accessible(1);
+            assert_eq!(Some(1), val.private_accessible);
+        }
+    }
+}
+
+#[test]
+fn test_plain() {
+    let mut val = Plain::default();
+    val.set_public_accessible(1);
+    assert_eq!(Some(1), val.public_accessible);
+    val.clear_public_accessible();
+    assert_eq!(None, val.public_accessible);
+}
+
+#[test]
+fn test_with() {
+    let val = Plain::default().with_public_accessible(1);
+    assert_eq!(Some(1), val.public_accessible);
+    let val = val.without_public_accessible();
+    assert_eq!(None, val.public_accessible);
+}
+
+#[test]
+fn test_list() {
+    let mut val = Plain::default();
+    val.set_listed(true);
+    assert_eq!(Some(true), val.listed);
+    let val = val.without_listed();
+    assert_eq!(None, val.listed);
+}
+
+#[test]
+fn test_generic() {
+    let mut val = Generic::<usize>::default();
+    val.set_public_accessible(1).clear_public_accessible();
+    assert_eq!(None, val.public_accessible);
+    let val = val.with_public_accessible(2);
+    assert_eq!(Some(2), val.public_accessible);
+}
+
+#[test]
+fn test_where() {
+    let mut val = Where::<usize>::default();
+    val.set_public_accessible(1).clear_public_accessible();
+    assert_eq!(None, val.public_accessible);
+    let val = val.with_public_accessible(2);
+    assert_eq!(Some(2), val.public_accessible);
+}
+
+#[test]
+fn test_chaining() {
+    let mut val = Plain::default();
+    val.set_public_accessible(1)
+        .set_second_public_accessible("second")
+        .clear_public_accessible();
+    assert_eq!(None, val.public_accessible);
+    assert_eq!(Some("second".to_string()), val.second_public_accessible);
+
+    let val = Plain::default()
+        .with_public_accessible(1)
+        .with_second_public_accessible("second")
+        .without_public_accessible();
+    assert_eq!(None, val.public_accessible);
+    assert_eq!(Some("second".to_string()), val.second_public_accessible);
+}
+
+#[test]
+fn test_unary_tuple() {
+    #[derive(Setters, WithSetters, Default)]
+    struct Maybe(#[getset(set = "strip_option", set_with = "strip_option")] Option<usize>);
+
+    let mut val = Maybe::default();
+    val.set(42);
+    assert_eq!(Some(42), val.0);
+    val.clear();
+    assert_eq!(None, val.0);
+    let val = val.set_with(43);
+    assert_eq!(Some(43), val.0);
+    let val = val.without();
+    assert_eq!(None, val.0);
+}
@@ -0,0 +1,167 @@
+#[macro_use]
+extern crate getset;
+
+use crate::submodule::other::{Generic, Plain, State, Where};
+
+// For testing `pub(super)`
+mod submodule {
+    // For testing `pub(in super::other)`
+    pub mod other {
+        #[derive(Setters, Default)]
+        #[getset(take, replace, swap)]
+        pub struct Plain {
+            /// A doc comment.
+            /// Multiple lines, even.
+            pub private_accessible: Vec<usize>,
+
+            /// A doc comment.
+            #[getset(take = "pub", replace = "pub", swap = "pub")]
+            pub public_accessible: Vec<usize>,
+
+            /// This field is used for testing swapping single fields.
+            #[getset(replace = "pub", swap = "pub")]
+            pub state: State,
+        }
+
+        #[derive(Debug, Default, PartialEq)]
+        pub enum State {
+            #[default]
+            Idle,
+            Running(usize),
+        }
+
+        #[derive(Setters, Default)]
+        #[getset(take, replace, swap)]
+        pub struct Generic<T: Default> {
+            /// A doc comment.
+            /// Multiple lines, even.
+            pub private_accessible: T,
+
+            /// A doc comment.
+            #[getset(take = "pub", replace = "pub", swap = "pub")]
+            pub public_accessible: T,
+        }
+
+        #[derive(Setters, Default)]
+        #[getset(take, replace, swap)]
+        pub struct Where<T>
+        where
+            T: Default,
+        {
+            /// A doc comment.
+            /// Multiple lines, even.
+            pub private_accessible: T,
+
+            /// A doc comment.
+            #[getset(take = "pub", replace = "pub", swap = "pub")]
+            pub public_accessible: T,
+        }
+
+        #[test]
+        fn test_plain() {
+            let mut val = Plain::default();
+            assert!(val.replace_private_accessible(vec![1]).is_empty());
+            assert_eq!(vec![1], val.take_private_accessible());
+            assert!(val.private_accessible.is_empty());
+        }
+
+        #[test]
+        fn test_generic() {
+            let mut val = Generic::<usize>::default();
+            assert_eq!(0, val.replace_private_accessible(1));
+            assert_eq!(1, val.take_private_accessible());
+        }
+
+        #[test]
+        fn test_where() {
+            let mut val = Where::<usize>::default();
+            assert_eq!(0, val.replace_private_accessible(1));
+            assert_eq!(1, val.take_private_accessible());
+        }
+    }
+}
+
+#[test]
+fn test_take() {
+    let mut val = Plain::default();
+    val.public_accessible.push(1);
+    assert_eq!(vec![1], val.take_public_accessible());
+    assert!(val.public_accessible.is_empty());
+}
+
+#[test]
+fn test_take_on_struct() {
+    struct NoDefault;
+
+    #[derive(Setters)]
+    #[getset(take, replace)]
+    struct Mixed {
+        count: usize,
+        other: NoDefault,
+    }
+
+    let mut val = Mixed {
+        count: 1,
+        other: NoDefault,
+    };
+    assert_eq!(1, val.take_count());
+    assert_eq!(0, val.count);
+    let NoDefault = val.replace_other(NoDefault);
+}
+
+#[test]
+fn test_replace() {
+    let mut val = Plain::default();
+    assert_eq!(State::Idle, val.replace_state(State::Running(1)));
+    assert_eq!(State::Running(1), val.replace_state(State::Idle));
+    assert_eq!(State::Idle, val.state);
+}
+
+#[test]
+fn test_swap() {
+    let mut first = Plain::default();
+    let mut second = Plain::default();
+    second.public_accessible.push(2);
+    second.state = State::Running(2);
+    first.swap_public_accessible(&mut second);
+    assert_eq!(vec![2], first.public_accessible);
+    assert!(second.public_accessible.is_empty());
+    // Only the swapped field changes hands.
+    assert_eq!(State::Idle, first.state);
+    first.swap_state(&mut second);
+    assert_eq!(State::Running(2), first.state);
+}
+
+#[test]
+fn test_generic() {
+    let mut val = Generic::<usize>::default();
+    let mut other = Generic::<usize>::default();
+    val.replace_public_accessible(1);
+    val.swap_public_accessible(&mut other);
+    assert_eq!(1, other.take_public_accessible());
+    assert_eq!(0, other.public_accessible);
+}
+
+#[test]
+fn test_where() {
+    let mut val = Where::<usize>::default();
+    let mut other = Where::<usize>::default();
+    val.replace_public_accessible(1);
+    val.swap_public_accessible(&mut other);
+    assert_eq!(1, other.take_public_accessible());
+    assert_eq!(0, other.public_accessible);
+}
+
+#[test]
+fn test_unary_tuple() {
+    #[derive(Setters, Default)]
+    struct Buffer(#[getset(take, replace, swap)] Vec<u8>);
+
+    let mut val = Buffer::default();
+    let mut other = Buffer(vec![1]);
+    assert!(val.replace(vec![2]).is_empty());
+    val.swap(&mut other);
+    assert_eq!(vec![2], other.0);
+    assert_eq!(vec![1], val.take());
+    assert!(val.0.is_empty());
+}
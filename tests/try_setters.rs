@@ -0,0 +1,141 @@
+#[macro_use]
+extern crate getset;
+
+use std::num::TryFromIntError;
+
+use crate::submodule::other::{Generic, Plain, Where};
+
+// For testing `pub(super)`
+mod submodule {
+    // For testing `pub(in super::other)`
+    pub mod other {
+        #[derive(Setters, Default)]
+        #[set_try]
+        pub struct Plain {
+            /// A doc comment.
+            /// Multiple lines, even.
+            pub private_accessible: u16,
+
+            /// A doc comment.
+            #[set_try = "pub"]
+            pub public_accessible: u16,
+
+            /// This field is used for testing chaining.
+            #[getset(set_try = "pub")]
+            pub second_public_accessible: u8,
+        }
+
+        #[derive(Setters, Default)]
+        #[set_try]
+        pub struct Generic<T: Default> {
+            /// A doc comment.
+            /// Multiple lines, even.
+            pub private_accessible: T,
+
+            /// A doc comment.
+            #[set_try = "pub"]
+            pub public_accessible: T,
+        }
+
+        #[derive(Setters, Default)]
+        #[set_try]
+        pub struct Where<T>
+        where
+            T: Default,
+        {
+            /// A doc comment.
+            /// Multiple lines, even.
+            pub private_accessible: T,
+
+            /// A doc comment.
+            #[set_try = "pub"]
+            pub public_accessible: T,
+        }
+
+        #[test]
+        fn test_plain() {
+            let mut val = Plain::default();
+            assert!(val.try_set_private_accessible(1u64).is_ok());
+            assert_eq!(1, val.private_accessible);
+        }
+
+        #[test]
+        fn test_generic() {
+            let mut val = Generic::<u16>::default();
+            assert!(val.try_set_private_accessible(1u64).is_ok());
+        }
+
+        #[test]
+        fn test_where() {
+            let mut val = Where::<u16>::default();
+            assert!(val.try_set_private_accessible(1u64).is_ok());
+        }
+    }
+}
+
+#[test]
+fn test_plain() {
+    let mut val = Plain::default();
+    assert!(val.try_set_public_accessible(8080u64).is_ok());
+    assert_eq!(8080, val.public_accessible);
+}
+
+#[test]
+fn test_failure_keeps_value() {
+    let mut val = Plain::default();
+    val.try_set_public_accessible(1u64).unwrap();
+    let error: Result<_, TryFromIntError> = val.try_set_public_accessible(u64::MAX).map(|_| ());
+    assert!(error.is_err());
+    assert_eq!(1, val.public_accessible);
+}
+
+#[test]
+fn test_with() {
+    let val = Plain::default()
+        .try_with_public_accessible(8080u64)
+        .unwrap();
+    assert_eq!(8080, val.public_accessible);
+    assert!(val.try_with_public_accessible(-1i32).is_err());
+}
+
+#[test]
+fn test_generic() {
+    let mut val = Generic::<u16>::default();
+    assert!(val.try_set_public_accessible(70_000u32).is_err());
+    let val = val.try_with_public_accessible(7u32).unwrap();
+    assert_eq!(7, val.public_accessible);
+}
+
+#[test]
+fn test_where() {
+    let mut val = Where::<u16>::default();
+    assert!(val.try_set_public_accessible(70_000u32).is_err());
+    let val = val.try_with_public_accessible(7u32).unwrap();
+    assert_eq!(7, val.public_accessible);
+}
+
+#[test]
+fn test_chaining() -> Result<(), TryFromIntError> {
+    let mut val = Plain::default();
+    val.try_set_public_accessible(1u64)?
+        .try_set_second_public_accessible(2u64)?;
+    assert_eq!(2, val.second_public_accessible);
+
+    let val = Plain::default()
+        .try_with_public_accessible(3u64)?
+        .try_with_second_public_accessible(4u64)?;
+    assert_eq!(4, val.second_public_accessible);
+    Ok(())
+}
+
+#[test]
+fn test_unary_tuple() {
+    #[derive(Setters, Default)]
+    struct Port(#[getset(set_try)] u16);
+
+    let mut val = Port::default();
+    assert!(val.try_set(8080u64).is_ok());
+    assert!(val.try_set(-1i64).is_err());
+    let val = val.try_set_with(80u64).unwrap();
+    assert_eq!(80, val.0);
+}
@@ -0,0 +1,163 @@
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::AtomicU8;
+
+use getset::{
+    AtomicAccessors, CellAccessors, CollectionAccessors, CopyGetters, Getters, MutGetters, Setters,
+    SharedAccessors, WithSetters,
+};
+
+use crate::submodule::other::Point;
+
+// For testing `pub(super)`
+mod submodule {
+    // For testing `pub(in super::other)`
+    pub mod other {
+        use getset::{CopyGetters, Setters};
+
+        #[derive(CopyGetters, Setters, Default)]
+        pub struct Point(
+            #[getset(get_copy = "pub", set = "pub")] f32,
+            #[getset(get_copy = "pub(super)", set = "pub(crate)")] f32,
+            #[getset(get_copy = "pub(in super::other)")] f32,
+        );
+
+        #[test]
+        fn test_point() {
+            let mut point = Point::default();
+            point.set_1(2.0);
+            assert_eq!(2.0, point.get_1());
+            assert_eq!(0.0, point.get_2());
+        }
+    }
+}
+
+#[test]
+fn test_positional_tuple() {
+    #[derive(Setters, Getters, MutGetters, WithSetters)]
+    struct Pair(
+        #[getset(set, get, get_mut, set_with)] i32,
+        #[getset(set, get, get_mut, set_with)] String,
+    );
+
+    let mut pair = Pair(42, "foo".to_string());
+    assert_eq!(pair.get_0(), &42);
+    assert_eq!(pair.get_1(), "foo");
+    *pair.get_mut_0() += 1;
+    pair.set_1("bar".to_string());
+    assert_eq!(pair.get_0(), &43);
+    assert_eq!(pair.get_1(), "bar");
+    pair = pair.set_with_0(44).set_with_1("baz".to_string());
+    assert_eq!(pair.get_0(), &44);
+    assert_eq!(pair.get_1(), "baz");
+
+    #[derive(CopyGetters)]
+    struct CopyPair(#[getset(get_copy)] i32, #[getset(get_copy)] i32);
+
+    let pair = CopyPair(1, 2);
+    assert_eq!(pair.get_0(), 1);
+    assert_eq!(pair.get_1(), 2);
+}
+
+#[test]
+fn test_positional_tuple_with_attrs() {
+    #[derive(Setters, Getters, MutGetters, WithSetters)]
+    #[getset(set, get, get_mut, set_with)]
+    struct Pair<T>(i32, #[getset(skip)] bool, T);
+
+    let mut pair = Pair(42, true, 'a');
+    assert_eq!(pair.get_0(), &42);
+    assert_eq!(pair.get_2(), &'a');
+    pair.set_0(43).set_2('b');
+    assert!(pair.1);
+    assert_eq!(pair.get_0(), &43);
+    assert_eq!(pair.get_mut_2(), &mut 'b');
+}
+
+#[test]
+fn test_positional_companions() {
+    #[derive(Getters, Setters)]
+    #[getset(get_option(or_default), set(strip_option))]
+    struct Pair(Option<i32>, Option<i32>);
+
+    let mut pair = Pair(None, Some(2));
+    assert_eq!(pair.get_or_default_0(), 0);
+    assert_eq!(pair.get_1(), Some(&2));
+    pair.set_0(1).clear_1();
+    assert_eq!(pair.get_0(), Some(&1));
+    assert_eq!(pair.get_1(), None);
+}
+
+#[test]
+fn test_named_tuple() {
+    #[derive(CopyGetters, Setters, WithSetters, Default)]
+    #[getset(set, set_with)]
+    struct Vector(
+        #[getset(get_copy(name = "x"), set(name = "set_x"), set_with(name = "with_x"))] f32,
+        #[getset(get_copy(name = "y"), set(name = "set_y"), set_with(name = "with_y"))] f32,
+        #[getset(get_copy(name = "z"))] f32,
+    );
+
+    let mut vector = Vector::default().with_x(1.0).with_y(2.0);
+    vector.set_2(3.0);
+    assert_eq!((1.0, 2.0, 3.0), (vector.x(), vector.y(), vector.z()));
+    vector.set_x(4.0).set_y(5.0);
+    assert_eq!((4.0, 5.0), (vector.x(), vector.y()));
+
+    #[derive(Getters, Setters)]
+    struct Labels(
+        #[getset(
+            get_option(name = "first", or_default),
+            set(name = "set_first", strip_option)
+        )]
+        Option<String>,
+        #[getset(get_option(name = "second"))] Option<String>,
+    );
+
+    let mut labels = Labels(None, Some("b".to_string()));
+    assert_eq!(labels.first_or_default(), "");
+    labels.set_first("a".to_string());
+    assert_eq!(labels.first(), Some(&"a".to_string()));
+    labels.clear_first();
+    assert_eq!(labels.first(), None);
+    assert_eq!(labels.second(), Some(&"b".to_string()));
+}
+
+#[test]
+fn test_positional_alongside_getters() {
+    #[derive(Getters, Setters, CollectionAccessors, Default)]
+    #[getset(get, set, collection)]
+    struct Pair(Vec<u8>, HashMap<String, u8>);
+
+    let mut pair = Pair::default();
+    pair.push_0(1);
+    pair.insert_1("one".to_string(), 1);
+    assert_eq!(&[1], pair.get_0().as_slice());
+    assert_eq!(Some(&1), pair.lookup_1("one"));
+    pair.set_1(HashMap::new());
+    assert!(pair.get_1().is_empty());
+
+    #[derive(Getters, Setters, CellAccessors, AtomicAccessors, SharedAccessors)]
+    #[getset(get, set)]
+    struct Interior(
+        #[getset(cell)] Cell<u8>,
+        #[getset(atomic = "fetch_add swap")] AtomicU8,
+        #[getset(shared)] Arc<u8>,
+    );
+
+    let interior = Interior(Cell::new(1), AtomicU8::new(2), Arc::new(3));
+    interior.set_value_0(4).set_value_1(5).fetch_add_value_1(1);
+    assert_eq!(4, interior.value_0());
+    assert_eq!(6, interior.swap_value_1(7));
+    assert_eq!(7, interior.value_1());
+    assert_eq!(&3, interior.value_2());
+    assert_eq!(4, interior.get_0().get());
+}
+
+#[test]
+fn test_point() {
+    let mut point = Point::default();
+    point.set_0(1.0);
+    assert_eq!(1.0, point.get_0());
+}
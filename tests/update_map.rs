@@ -0,0 +1,138 @@
+#[macro_use]
+extern crate getset;
+
+use crate::submodule::other::{Generic, Plain, Where};
+
+// For testing `pub(super)`
+mod submodule {
+    // For testing `pub(in super::other)`
+    pub mod other {
+        #[derive(MutGetters, WithSetters, Default)]
+        #[getset(update, map)]
+        pub struct Plain {
+            /// A doc comment.
+            /// Multiple lines, even.
+            pub private_accessible: usize,
+
+            /// A doc comment.
+            #[getset(update = "pub", map = "pub")]
+            pub public_accessible: usize,
+
+            /// This field is used for testing chaining.
+            #[getset(update = "pub", map = "pub", set_with = "pub")]
+            pub second_public_accessible: Vec<usize>,
+        }
+
+        #[derive(MutGetters, WithSetters, Default)]
+        #[getset(update, map)]
+        pub struct Generic<T: Default> {
+            /// A doc comment.
+            /// Multiple lines, even.
+            pub private_accessible: T,
+
+            /// A doc comment.
+            #[getset(update = "pub", map = "pub")]
+            pub public_accessible: T,
+        }
+
+        #[derive(MutGetters, WithSetters, Default)]
+        #[getset(update, map)]
+        pub struct Where<T>
+        where
+            T: Default,
+        {
+            /// A doc comment.
+            /// Multiple lines, even.
+            pub private_accessible: T,
+
+            /// A doc comment.
+            #[getset(update = "pub", map = "pub")]
+            pub public_accessible: T,
+        }
+
+        #[test]
+        fn test_plain() {
+            let mut val = Plain::default();
+            val.update_private_accessible(|v| *v += 1);
+            assert_eq!(1, val.private_accessible);
+            let val = val.map_private_accessible(|v| v * 10);
+            assert_eq!(10, val.private_accessible);
+        }
+
+        #[test]
+        fn test_generic() {
+            let mut val = Generic::<usize>::default();
+            val.update_private_accessible(|v| *v += 1);
+            assert_eq!(1, val.private_accessible);
+        }
+
+        #[test]
+        fn test_where() {
+            let mut val = Where::<usize>::default();
+            val.update_private_accessible(|v| *v += 1);
+            assert_eq!(1, val.private_accessible);
+        }
+    }
+}
+
+#[test]
+fn test_update() {
+    let mut val = Plain::default();
+    val.update_public_accessible(|v| *v += 2);
+    assert_eq!(2, val.public_accessible);
+}
+
+#[test]
+fn test_map() {
+    let val = Plain::default().map_second_public_accessible(|mut items| {
+        items.push(1);
+        items
+    });
+    assert_eq!(vec![1], val.second_public_accessible);
+}
+
+#[test]
+fn test_generic() {
+    let mut val = Generic::<usize>::default();
+    val.update_public_accessible(|v| *v += 1);
+    let val = val.map_public_accessible(|v| v + 1);
+    assert_eq!(2, val.public_accessible);
+}
+
+#[test]
+fn test_where() {
+    let mut val = Where::<usize>::default();
+    val.update_public_accessible(|v| *v += 1);
+    let val = val.map_public_accessible(|v| v + 1);
+    assert_eq!(2, val.public_accessible);
+}
+
+#[test]
+fn test_chaining() {
+    let mut val = Plain::default();
+    val.update_public_accessible(|v| *v += 1)
+        .update_second_public_accessible(|items| items.push(1));
+    assert_eq!(1, val.public_accessible);
+    assert_eq!(vec![1], val.second_public_accessible);
+
+    let val = Plain::default()
+        .with_second_public_accessible(vec![3, 1, 2])
+        .map_second_public_accessible(|mut items| {
+            items.sort();
+            items
+        })
+        .map_public_accessible(|v| v + 1);
+    assert_eq!(vec![1, 2, 3], val.second_public_accessible);
+    assert_eq!(1, val.public_accessible);
+}
+
+#[test]
+fn test_unary_tuple() {
+    #[derive(MutGetters, WithSetters, Default)]
+    struct Counter(#[getset(update, map)] usize);
+
+    let mut val = Counter::default();
+    val.update(|v| *v += 1).update(|v| *v += 1);
+    let val = val.map(|v| v * 2);
+    assert_eq!(4, val.0);
+}
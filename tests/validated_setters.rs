@@ -0,0 +1,182 @@
+#[macro_use]
+extern crate getset;
+
+use crate::submodule::other::{Generic, Plain, Where};
+
+#[derive(Debug, PartialEq)]
+pub struct OutOfRange;
+
+pub fn check_port(port: &u16) -> Result<(), OutOfRange> {
+    if *port >= 1024 {
+        Ok(())
+    } else {
+        Err(OutOfRange)
+    }
+}
+
+pub fn check_not_empty<T>(items: &[T]) -> Result<(), String> {
+    if items.is_empty() {
+        Err("empty".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+// For testing `pub(super)`
+mod submodule {
+    // For testing `pub(in super::other)`
+    pub mod other {
+        use crate::OutOfRange;
+
+        #[derive(Setters, WithSetters, Default)]
+        #[getset(
+            set(validate = "crate::check_port", error = "OutOfRange"),
+            set_with(validate = "crate::check_port", error = "OutOfRange")
+        )]
+        pub struct Plain {
+            /// A doc comment.
+            /// Multiple lines, even.
+            pub private_accessible: u16,
+
+            /// A doc comment.
+            #[getset(
+                set(vis = "pub", validate = "crate::check_port", error = "OutOfRange"),
+                set_with(vis = "pub", validate = "crate::check_port", error = "OutOfRange")
+            )]
+            pub public_accessible: u16,
+
+            /// This field is used for testing conversions.
+            #[getset(set(
+                vis = "pub",
+                into,
+                validate = "crate::check_port",
+                error = "OutOfRange"
+            ))]
+            pub converted: u16,
+        }
+
+        #[derive(Setters, WithSetters, Default)]
+        pub struct Generic<T: Default> {
+            /// A doc comment.
+            /// Multiple lines, even.
+            #[getset(set(validate = "crate::check_not_empty", error = "String"))]
+            pub private_accessible: Vec<T>,
+
+            /// A doc comment.
+            #[getset(
+                set(vis = "pub", validate = "crate::check_not_empty", error = "String"),
+                set_with(vis = "pub", validate = "crate::check_not_empty", error = "String")
+            )]
+            pub public_accessible: Vec<T>,
+        }
+
+        #[derive(Setters, WithSetters, Default)]
+        pub struct Where<T>
+        where
+            T: Default,
+        {
+            /// A doc comment.
+            /// Multiple lines, even.
+            #[getset(set(validate = "crate::check_not_empty", error = "String"))]
+            pub private_accessible: Vec<T>,
+
+            /// A doc comment.
+            #[getset(
+                set(vis = "pub", validate = "crate::check_not_empty", error = "String"),
+                set_with(vis = "pub", validate = "crate::check_not_empty", error = "String")
+            )]
+            pub public_accessible: Vec<T>,
+        }
+
+        #[test]
+        fn test_plain() {
+            let mut val = Plain::default();
+            assert_eq!(Err(OutOfRange), val.set_private_accessible(80).map(|_| ()));
+            assert!(val.set_private_accessible(8080).is_ok());
+            assert_eq!(8080, val.private_accessible);
+        }
+
+        #[test]
+        fn test_generic() {
+            let mut val = Generic::<usize>::default();
+            assert!(val.set_private_accessible(vec![]).is_err());
+            assert!(val.set_private_accessible(vec![1]).is_ok());
+        }
+
+        #[test]
+        fn test_where() {
+            let mut val = Where::<usize>::default();
+            assert!(val.set_private_accessible(vec![]).is_err());
+            assert!(val.set_private_accessible(vec![1]).is_ok());
+        }
+    }
+}
+
+#[test]
+fn test_plain() {
+    let mut val = Plain::default();
+    assert!(val.set_public_accessible(8080).is_ok());
+    assert_eq!(8080, val.public_accessible);
+}
+
+#[test]
+fn test_failure_keeps_value() {
+    let mut val = Plain::default();
+    val.set_public_accessible(8080).unwrap();
+    assert_eq!(Err(OutOfRange), val.set_public_accessible(80).map(|_| ()));
+    assert_eq!(8080, val.public_accessible);
+}
+
+#[test]
+fn test_with() {
+    let val = Plain::default().with_public_accessible(8080).unwrap();
+    assert_eq!(8080, val.public_accessible);
+    assert_eq!(
+        Err(OutOfRange),
+        val.with_public_accessible(80)
+            .map(|val| val.public_accessible)
+    );
+}
+
+#[test]
+fn test_into() {
+    let mut val = Plain::default();
+    assert!(val.set_converted(8u8).is_err());
+    assert!(val.set_converted(8080u16).is_ok());
+    assert_eq!(8080, val.converted);
+}
+
+#[test]
+fn test_generic() {
+    let mut val = Generic::<usize>::default();
+    assert_eq!(
+        Err("empty".to_string()),
+        val.set_public_accessible(vec![]).map(|_| ())
+    );
+    let val = val.with_public_accessible(vec![1]).unwrap();
+    assert_eq!(vec![1], val.public_accessible);
+}
+
+#[test]
+fn test_where() {
+    let mut val = Where::<usize>::default();
+    assert_eq!(
+        Err("empty".to_string()),
+        val.set_public_accessible(vec![]).map(|_| ())
+    );
+    let val = val.with_public_accessible(vec![1]).unwrap();
+    assert_eq!(vec![1], val.public_accessible);
+}
+
+#[test]
+fn test_chaining() -> Result<(), OutOfRange> {
+    let mut val = Plain::default();
+    val.set_public_accessible(8080)?.set_converted(8081u16)?;
+    assert_eq!(8081, val.converted);
+
+    let val = Plain::default()
+        .with_public_accessible(8080)?
+        .with_public_accessible(8081)?;
+    assert_eq!(8081, val.public_accessible);
+    Ok(())
+}
@@ -0,0 +1,81 @@
+#[macro_use]
+extern crate getset;
+
+use crate::submodule::other::Plain;
+
+// For testing `pub(super)`
+mod submodule {
+    use self::other::Plain;
+
+    // For testing `pub(in super::other)`
+    pub mod other {
+        #[derive(Getters, MutGetters, Setters, WithSetters, Default)]
+        pub struct Plain {
+            /// A doc comment.
+            #[getset(get = "pub(crate) with_prefix", set = "pub(crate) into")]
+            pub(crate) crate_accessible: String,
+
+            /// A doc comment.
+            #[getset(get = "with_prefix pub(super)", get_mut = "pub(super)")]
+            pub(super) super_accessible: usize,
+
+            /// A doc comment.
+            #[getset(
+                get = "pub(in super::other) with_prefix",
+                set_with = "pub(in super::other)"
+            )]
+            scope_accessible: usize,
+
+            /// A doc comment.
+            #[getset(get = "pub(in crate::submodule) with_prefix")]
+            crate_path_accessible: usize,
+
+            /// A doc comment.
+            #[getset(get = "pub(self) with_prefix", set = "pub(self) strip_option")]
+            self_accessible: Option<usize>,
+
+            /// A doc comment.
+            #[getset(get(vis = "pub(in crate::submodule)", prefix), set(vis = "pub(super)"))]
+            list_accessible: usize,
+
+            /// A doc comment.
+            #[getset(get_option = "pub(in super::other) or_default")]
+            optional: Option<usize>,
+        }
+
+        #[test]
+        fn test_scope() {
+            let val = Plain::default().with_scope_accessible(1);
+            assert_eq!(1, *val.get_scope_accessible());
+            assert_eq!(0, *val.get_crate_path_accessible());
+            assert_eq!(0, val.optional_or_default());
+            assert_eq!(None, val.optional());
+        }
+
+        #[test]
+        fn test_self() {
+            let mut val = Plain::default();
+            val.set_self_accessible(1);
+            assert_eq!(Some(1), *val.get_self_accessible());
+            val.clear_self_accessible();
+            assert_eq!(None, *val.get_self_accessible());
+        }
+    }
+
+    #[test]
+    fn test_super() {
+        let mut val = Plain::default();
+        *val.super_accessible_mut() += 1;
+        val.set_list_accessible(2);
+        assert_eq!(1, *val.get_super_accessible());
+        assert_eq!(2, *val.get_list_accessible());
+        assert_eq!(0, *val.get_crate_path_accessible());
+    }
+}
+
+#[test]
+fn test_crate() {
+    let mut val = Plain::default();
+    val.set_crate_accessible("value");
+    assert_eq!("value", val.get_crate_accessible());
+}
@@ -21,17 +21,18 @@ mod submodule {
             /// This field is used for testing chaining.
             #[set_with = "pub"]
             second_public_accessible: bool,
-            // /// A doc comment.
-            // #[set_with = "pub(crate)"]
-            // crate_accessible: usize,
 
-            // /// A doc comment.
-            // #[set_with = "pub(super)"]
-            // super_accessible: usize,
+            /// A doc comment.
+            #[set_with = "pub(crate)"]
+            crate_accessible: usize,
+
+            /// A doc comment.
+            #[set_with = "pub(super)"]
+            super_accessible: usize,
 
-            // /// A doc comment.
-            // #[set_with = "pub(in super::other)"]
-            // scope_accessible: usize,
+            /// A doc comment.
+            #[set_with = "pub(in super::other)"]
+            scope_accessible: usize,
         }
 
         #[derive(WithSetters, Default)]
@@ -44,17 +45,18 @@ mod submodule {
             /// A doc comment.
             #[set_with = "pub"]
             public_accessible: T,
-            // /// A doc comment.
-            // #[set_with = "pub(crate)"]
-            // crate_accessible: usize,
 
-            // /// A doc comment.
-            // #[set_with = "pub(super)"]
-            // super_accessible: usize,
+            /// A doc comment.
+            #[set_with = "pub(crate)"]
+            crate_accessible: usize,
+
+            /// A doc comment.
+            #[set_with = "pub(super)"]
+            super_accessible: usize,
 
-            // /// A doc comment.
-            // #[set_with = "pub(in super::other)"]
-            // scope_accessible: usize,
+            /// A doc comment.
+            #[set_with = "pub(in super::other)"]
+            scope_accessible: usize,
         }
 
         #[derive(WithSetters, Default)]
@@ -70,35 +72,48 @@ mod submodule {
             /// A doc comment.
             #[set_with = "pub"]
             public_accessible: T,
-            // /// A doc comment.
-            // #[set_with = "pub(crate)"]
-            // crate_accessible: usize,
 
-            // /// A doc comment.
-            // #[set_with = "pub(super)"]
-            // super_accessible: usize,
+            /// A doc comment.
+            #[set_with = "pub(crate)"]
+            crate_accessible: usize,
+
+            /// A doc comment.
+            #[set_with = "pub(super)"]
+            super_accessible: usize,
 
-            // /// A doc comment.
-            // #[set_with = "pub(in super::other)"]
-            // scope_accessible: usize,
+            /// A doc comment.
+            #[set_with = "pub(in super::other)"]
+            scope_accessible: usize,
         }
 
         #[test]
         fn test_plain() {
             let val: Plain = Plain::default();
-            let _: Plain = val.with_private_accessible(1);
+            let _: Plain = val
+                .with_private_accessible(1)
+                .with_crate_accessible(1)
+                .with_super_accessible(1)
+                .with_scope_accessible(1);
         }
 
         #[test]
         fn test_generic() {
             let val: Generic<i32> = Generic::default();
-            let _: Generic<i32> = val.with_private_accessible(1);
+            let _: Generic<i32> = val
+                .with_private_accessible(1)
+                .with_crate_accessible(1)
+                .with_super_accessible(1)
+                .with_scope_accessible(1);
         }
 
         #[test]
         fn test_where() {
             let val: Where<i32> = Where::default();
-            let _: Where<i32> = val.with_private_accessible(1);
+            let _: Where<i32> = val
+                .with_private_accessible(1)
+                .with_crate_accessible(1)
+                .with_super_accessible(1)
+                .with_scope_accessible(1);
         }
     }
 }